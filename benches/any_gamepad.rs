@@ -0,0 +1,80 @@
+//! Compares reading an action with 8 gamepad bindings from all gamepads and from an explicit set.
+//!
+//! [`GamepadDevice::Any`] reads input cached once per frame, while [`GamepadDevice::Set`]
+//! iterates over the gamepads for each binding, like [`GamepadDevice::Any`] did before the cache.
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn any_gamepad(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gamepad_bindings");
+
+    group.bench_function("any", |b| {
+        let mut app = app(|_| GamepadDevice::Any);
+        b.iter(|| app.update());
+    });
+
+    group.bench_function("set", |b| {
+        let mut app = app(GamepadDevice::from);
+        b.iter(|| app.update());
+    });
+
+    group.finish();
+}
+
+fn app(gamepad: impl FnOnce([Entity; GAMEPADS]) -> GamepadDevice) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>();
+
+    let gamepads = std::array::from_fn(|_| {
+        let mut gamepad = Gamepad::default();
+        gamepad.digital_mut().press(GamepadButton::South);
+        gamepad.analog_mut().set(GamepadAxis::LeftStickX, 0.5);
+        app.world_mut().spawn(gamepad).id()
+    });
+
+    let gamepad = gamepad(gamepads);
+    for _ in 0..PLAYERS {
+        app.world_mut().spawn(Player(gamepad.clone()));
+    }
+
+    app.update();
+
+    app
+}
+
+const GAMEPADS: usize = 4;
+const PLAYERS: usize = 16;
+
+#[derive(Component)]
+struct Player(GamepadDevice);
+
+impl InputContext for Player {
+    fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+        let player = world.get::<Self>(entity).unwrap();
+
+        let mut ctx = ContextInstance::default();
+        ctx.set_gamepad(player.0.clone());
+        ctx.bind::<Move>().to((
+            GamepadButton::South,
+            GamepadButton::East,
+            GamepadButton::North,
+            GamepadButton::West,
+            GamepadAxis::LeftStickX,
+            GamepadAxis::LeftStickY,
+            GamepadAxis::RightStickX,
+            GamepadAxis::RightStickY,
+        ));
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Move;
+
+criterion_group!(benches, any_gamepad);
+criterion_main!(benches);
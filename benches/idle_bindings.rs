@@ -0,0 +1,114 @@
+//! Compares updating many idle bindings with modifiers that preserve zero and with
+//! modifiers that always require evaluation.
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::{
+    action_value::ActionValue, input_context::context_instance::ActionsData, prelude::*,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn idle_bindings(c: &mut Criterion) {
+    let mut group = c.benchmark_group("idle_bindings");
+
+    group.bench_function("skipped", |b| {
+        let mut app = app(Player::Skipped);
+        b.iter(|| app.update());
+    });
+
+    group.bench_function("evaluated", |b| {
+        let mut app = app(Player::Evaluated);
+        b.iter(|| app.update());
+    });
+
+    group.finish();
+}
+
+fn app(player: Player) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>();
+
+    for _ in 0..PLAYERS {
+        app.world_mut().spawn(player);
+    }
+
+    app.update();
+
+    app
+}
+
+const PLAYERS: usize = 16;
+
+const KEYS: [KeyCode; 8] = [
+    KeyCode::KeyW,
+    KeyCode::KeyA,
+    KeyCode::KeyS,
+    KeyCode::KeyD,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowRight,
+];
+
+const BUTTONS: [GamepadButton; 8] = [
+    GamepadButton::South,
+    GamepadButton::East,
+    GamepadButton::North,
+    GamepadButton::West,
+    GamepadButton::DPadUp,
+    GamepadButton::DPadLeft,
+    GamepadButton::DPadDown,
+    GamepadButton::DPadRight,
+];
+
+/// Forwards to the inner modifier, but always requires evaluation.
+#[derive(Debug)]
+struct Opaque<M>(M);
+
+impl<M: InputModifier> InputModifier for Opaque<M> {
+    fn apply(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        self.0.apply(actions, time, value)
+    }
+}
+
+#[derive(Component, Clone, Copy)]
+enum Player {
+    Skipped,
+    Evaluated,
+}
+
+impl InputContext for Player {
+    fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+        let player = *world.get::<Self>(entity).unwrap();
+
+        let mut ctx = ContextInstance::default();
+        let inputs = KEYS
+            .map(Input::from)
+            .into_iter()
+            .chain(BUTTONS.map(Input::from));
+        for input in inputs {
+            match player {
+                Player::Skipped => ctx
+                    .bind::<Move>()
+                    .to(input.with_modifiers((Negate::all(), Scale::splat(2.0)))),
+                Player::Evaluated => ctx
+                    .bind::<Move>()
+                    .to(input.with_modifiers((Opaque(Negate::all()), Opaque(Scale::splat(2.0))))),
+            };
+        }
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Move;
+
+criterion_group!(benches, idle_bindings);
+criterion_main!(benches);
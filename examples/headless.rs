@@ -0,0 +1,81 @@
+//! Running without a window and real input, like on a dedicated server.
+//!
+//! Time is advanced manually and actions are driven by mocks instead of input devices.
+
+use std::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin, GamePlugin))
+        // Advance time by a fixed step on each update instead of measuring real time.
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+
+    let entity = app.world_mut().spawn(Unit).id();
+
+    // Contexts are instantiated on the next update after insertion.
+    app.update();
+
+    // Script the movement like an AI or a network replication would do.
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<Unit>(entity)
+        .bind::<Move>()
+        .mock(ActionMock::new(
+            ActionState::Fired,
+            Vec2::X,
+            MockSpan::Duration(Duration::from_millis(500)),
+        ))
+        .mock(ActionMock::new(
+            ActionState::Fired,
+            Vec2::Y,
+            MockSpan::Updates(3),
+        ));
+
+    // Step schedules manually instead of calling `App::run`.
+    for _ in 0..10 {
+        app.update();
+
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        println!("unit position: {}", transform.translation);
+    }
+}
+
+struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_input_context::<Unit>()
+            .add_observer(Self::apply_movement);
+    }
+}
+
+impl GamePlugin {
+    fn apply_movement(trigger: Trigger<Fired<Move>>, mut units: Query<&mut Transform>) {
+        let mut transform = units.get_mut(trigger.entity()).unwrap();
+        transform.translation += trigger.value.extend(0.0);
+    }
+}
+
+#[derive(Component)]
+#[require(Transform)]
+struct Unit;
+
+impl InputContext for Unit {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        // Bindings are still useful if the same context is used on a client.
+        ctx.bind::<Move>().to(Cardinal::wasd_keys());
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Move;
@@ -0,0 +1,143 @@
+//! Two players that share the same context type, but one uses only the keyboard and the other uses only a gamepad.
+
+mod player_box;
+
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::{
+    color::palettes::tailwind::{BLUE_600, RED_600},
+    input::gamepad::{GamepadConnection, GamepadConnectionEvent},
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+
+use player_box::{PlayerBox, PlayerBoxPlugin, PlayerColor, DEFAULT_SPEED};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            EnhancedInputPlugin,
+            PlayerBoxPlugin,
+            GamePlugin,
+        ))
+        .run();
+}
+
+struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerGamepad>()
+            .add_input_context::<PlayerBox>()
+            .add_observer(Self::apply_movement)
+            .add_observer(Self::rotate)
+            .add_systems(Startup, Self::spawn)
+            .add_systems(Update, Self::update_gamepad);
+    }
+}
+
+impl GamePlugin {
+    fn spawn(mut commands: Commands) {
+        commands.spawn(Camera2d);
+
+        commands.spawn((
+            PlayerBox,
+            Transform::from_translation(Vec3::X * 50.0),
+            PlayerColor(RED_600.into()),
+            Device::Keyboard,
+        ));
+        commands.spawn((
+            PlayerBox,
+            Transform::from_translation(-Vec3::X * 50.0),
+            PlayerColor(BLUE_600.into()),
+            Device::Gamepad,
+        ));
+    }
+
+    fn apply_movement(trigger: Trigger<Fired<Move>>, mut players: Query<&mut Transform>) {
+        let event = trigger.event();
+        let mut transform = players.get_mut(trigger.entity()).unwrap();
+        transform.translation += event.value.extend(0.0);
+    }
+
+    fn rotate(trigger: Trigger<Started<Rotate>>, mut players: Query<&mut Transform>) {
+        let mut transform = players.get_mut(trigger.entity()).unwrap();
+        transform.rotate_z(FRAC_PI_4);
+    }
+
+    fn update_gamepad(
+        mut commands: Commands,
+        mut connect_events: EventReader<GamepadConnectionEvent>,
+        mut player_gamepad: ResMut<PlayerGamepad>,
+    ) {
+        for event in connect_events.read() {
+            match event.connection {
+                GamepadConnection::Connected { .. } => {
+                    if player_gamepad.is_none() {
+                        **player_gamepad = Some(event.gamepad);
+                        commands.trigger(RebuildInputContexts);
+                    }
+                }
+                GamepadConnection::Disconnected => {
+                    if **player_gamepad == Some(event.gamepad) {
+                        **player_gamepad = None;
+                        commands.trigger(RebuildInputContexts);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Input device that controls the player.
+#[derive(Component, Clone, Copy)]
+enum Device {
+    Keyboard,
+    Gamepad,
+}
+
+/// Gamepad assigned to the gamepad player.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct PlayerGamepad(Option<Entity>);
+
+impl InputContext for PlayerBox {
+    fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        // Both players use the same bindings, but read only from their devices.
+        match *world.get::<Device>(entity).unwrap() {
+            Device::Keyboard => {
+                // By default context read inputs from all gamepads,
+                // so we need to explicitly ignore them.
+                ctx.set_gamepad(GamepadDevice::None);
+            }
+            Device::Gamepad => {
+                // Keyboard and mouse are global, so we need to explicitly ignore them.
+                ctx.set_keyboard_mouse(false);
+                let gamepad = world.resource::<PlayerGamepad>();
+                ctx.set_gamepad(gamepad.map_or(GamepadDevice::None, GamepadDevice::Single));
+            }
+        }
+
+        ctx.bind::<Move>()
+            .to((Cardinal::wasd_keys(), GamepadStick::Left))
+            .with_modifiers((
+                DeadZone::default(),
+                SmoothNudge::default(),
+                Scale::splat(DEFAULT_SPEED),
+            ));
+        ctx.bind::<Rotate>()
+            .to((KeyCode::Space, GamepadButton::South));
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Move;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Rotate;
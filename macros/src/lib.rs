@@ -13,6 +13,18 @@ struct InputActionOpts {
     consume_input: Option<bool>,
     #[darling(default)]
     require_reset: Option<bool>,
+    #[darling(default)]
+    emit_ongoing: Option<bool>,
+    #[darling(default)]
+    edge_triggered: Option<bool>,
+    #[darling(default)]
+    device_lock: Option<bool>,
+    #[darling(default)]
+    persist_timing: Option<bool>,
+    #[darling(default)]
+    block_by_mod_keys: Option<bool>,
+    #[darling(default)]
+    emit_untyped: Option<bool>,
 }
 
 #[proc_macro_derive(InputAction, attributes(input_action))]
@@ -56,6 +68,54 @@ pub fn input_action_derive(item: TokenStream) -> TokenStream {
         Default::default()
     };
 
+    let emit_ongoing = if let Some(emit) = opts.emit_ongoing {
+        quote! {
+            const EMIT_ONGOING: bool = #emit;
+        }
+    } else {
+        Default::default()
+    };
+
+    let edge_triggered = if let Some(edge) = opts.edge_triggered {
+        quote! {
+            const EDGE_TRIGGERED: bool = #edge;
+        }
+    } else {
+        Default::default()
+    };
+
+    let device_lock = if let Some(lock) = opts.device_lock {
+        quote! {
+            const DEVICE_LOCK: bool = #lock;
+        }
+    } else {
+        Default::default()
+    };
+
+    let persist_timing = if let Some(persist) = opts.persist_timing {
+        quote! {
+            const PERSIST_TIMING: bool = #persist;
+        }
+    } else {
+        Default::default()
+    };
+
+    let block_by_mod_keys = if let Some(block) = opts.block_by_mod_keys {
+        quote! {
+            const BLOCK_BY_MOD_KEYS: bool = #block;
+        }
+    } else {
+        Default::default()
+    };
+
+    let emit_untyped = if let Some(emit) = opts.emit_untyped {
+        quote! {
+            const EMIT_UNTYPED: bool = #emit;
+        }
+    } else {
+        Default::default()
+    };
+
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
 
     TokenStream::from(quote! {
@@ -64,6 +124,12 @@ pub fn input_action_derive(item: TokenStream) -> TokenStream {
             #accumulation
             #consume_input
             #require_reset
+            #emit_ongoing
+            #edge_triggered
+            #device_lock
+            #persist_timing
+            #block_by_mod_keys
+            #emit_untyped
         }
     })
 }
@@ -1,10 +1,10 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display, Formatter};
 
 use bevy::prelude::*;
-use serde::{Deserialize, Serialize};
 
 /// Value for [`Input`](crate::input::Input).
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActionValue {
     Bool(bool),
     Axis1D(f32),
@@ -37,7 +37,13 @@ impl ActionValue {
     ///
     /// If the new dimension is larger, the additional axes will be set to zero.
     /// If the new dimension is smaller, the extra axes will be discarded.
+    /// In debug builds, discarding non-zero axes is logged at the debug level
+    /// to help catch misconfigured modifiers, like a wrong [`SwizzleAxis`](crate::input_context::input_modifier::swizzle_axis::SwizzleAxis).
     pub fn convert(self, dim: ActionValueDim) -> Self {
+        if cfg!(debug_assertions) && self.truncates(dim) {
+            debug!("converting `{self:?}` into `{dim:?}` discards non-zero axes");
+        }
+
         match dim {
             ActionValueDim::Bool => self.as_bool().into(),
             ActionValueDim::Axis1D => self.as_axis1d().into(),
@@ -46,11 +52,47 @@ impl ActionValue {
         }
     }
 
+    /// Like [`Self::convert`], but converts into [`ActionValueDim::Bool`] using a threshold.
+    ///
+    /// Values with a magnitude below `threshold` become `false` instead of only zero values.
+    /// A threshold of zero or less behaves like [`Self::convert`].
+    /// Other dimensions ignore the threshold.
+    pub fn convert_with_threshold(self, dim: ActionValueDim, threshold: f32) -> Self {
+        match dim {
+            ActionValueDim::Bool if threshold > 0.0 => self.is_actuated(threshold).into(),
+            _ => self.convert(dim),
+        }
+    }
+
+    /// Returns `true` if converting into the specified dimension discards non-zero axes.
+    fn truncates(self, dim: ActionValueDim) -> bool {
+        let value = self.as_axis3d();
+        match dim {
+            ActionValueDim::Bool | ActionValueDim::Axis3D => false,
+            ActionValueDim::Axis1D => value.y != 0.0 || value.z != 0.0,
+            ActionValueDim::Axis2D => value.z != 0.0,
+        }
+    }
+
     /// Returns `true` if the value in sufficiently large.
     pub fn is_actuated(self, actuation: f32) -> bool {
         self.as_axis3d().length_squared() >= actuation * actuation
     }
 
+    /// Scales the value down to `max` if its length exceeds it, preserving the direction.
+    ///
+    /// The sign of `max` is ignored. [`ActionValue::Bool`] is returned unchanged.
+    #[must_use]
+    pub fn clamp_magnitude(self, max: f32) -> Self {
+        let max = max.abs();
+        match self {
+            Self::Bool(_) => self,
+            Self::Axis1D(value) => value.clamp(-max, max).into(),
+            Self::Axis2D(value) => value.clamp_length_max(max).into(),
+            Self::Axis3D(value) => value.clamp_length_max(max).into(),
+        }
+    }
+
     /// Returns the value as a boolean.
     ///
     /// If the value is not [`ActionValue::Bool`],
@@ -125,7 +167,8 @@ impl ActionValue {
 }
 
 /// A dimension discriminant for [`ActionValue`].
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActionValueDim {
     Bool,
     Axis1D,
@@ -133,6 +176,28 @@ pub enum ActionValueDim {
     Axis3D,
 }
 
+/// Formats the value compactly for logging and debug UIs.
+///
+/// Axes are printed with 2 decimal places unless a precision is specified:
+/// `true`, `0.30`, `Vec2(0.30, -0.50)`, `Vec3(0.30, -0.50, 1.00)`.
+impl Display for ActionValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        match self {
+            ActionValue::Bool(value) => write!(f, "{value}"),
+            ActionValue::Axis1D(value) => write!(f, "{value:.precision$}"),
+            ActionValue::Axis2D(value) => {
+                write!(f, "Vec2({:.precision$}, {:.precision$})", value.x, value.y)
+            }
+            ActionValue::Axis3D(value) => write!(
+                f,
+                "Vec3({:.precision$}, {:.precision$}, {:.precision$})",
+                value.x, value.y, value.z
+            ),
+        }
+    }
+}
+
 impl From<bool> for ActionValue {
     fn from(value: bool) -> Self {
         ActionValue::Bool(value)
@@ -217,4 +282,97 @@ mod tests {
         assert_eq!(value.convert(ActionValueDim::Axis2D), Vec2::ONE.into());
         assert_eq!(value.convert(ActionValueDim::Axis3D), Vec3::ONE.into());
     }
+
+    #[test]
+    fn clamp_magnitude() {
+        let value = ActionValue::Axis2D(Vec2::ONE)
+            .clamp_magnitude(0.5)
+            .as_axis2d();
+        assert!((value.length() - 0.5).abs() < f32::EPSILON);
+        assert!((value.to_angle() - Vec2::ONE.to_angle()).abs() < f32::EPSILON);
+
+        let value = ActionValue::Axis2D(Vec2::new(0.3, 0.0));
+        assert_eq!(value.clamp_magnitude(1.0), value);
+        assert_eq!(
+            ActionValue::Axis1D(-2.0).clamp_magnitude(1.0),
+            (-1.0).into()
+        );
+        assert_eq!(ActionValue::Bool(true).clamp_magnitude(0.5), true.into());
+
+        assert_eq!(
+            ActionValue::Axis1D(2.0).clamp_magnitude(-1.0),
+            1.0.into(),
+            "negative limit should behave like a positive one"
+        );
+        assert_eq!(
+            ActionValue::Axis2D(Vec2::new(0.0, 2.0)).clamp_magnitude(-1.0),
+            Vec2::Y.into()
+        );
+    }
+
+    #[test]
+    fn threshold_conversion() {
+        let value = ActionValue::Axis1D(0.05);
+        assert_eq!(
+            value.convert_with_threshold(ActionValueDim::Bool, 0.3),
+            false.into()
+        );
+        assert_eq!(
+            value.convert_with_threshold(ActionValueDim::Bool, 0.0),
+            true.into()
+        );
+        assert_eq!(
+            value.convert_with_threshold(ActionValueDim::Axis1D, 0.3),
+            0.05.into()
+        );
+
+        let value = ActionValue::Axis2D((0.3, 0.4).into());
+        assert_eq!(
+            value.convert_with_threshold(ActionValueDim::Bool, 0.5),
+            true.into()
+        );
+        assert_eq!(
+            ActionValue::Bool(true).convert_with_threshold(ActionValueDim::Bool, 0.3),
+            true.into()
+        );
+    }
+
+    #[test]
+    fn truncation() {
+        let value = ActionValue::Axis2D((0.0, 1.0).into());
+        assert!(value.truncates(ActionValueDim::Axis1D));
+        assert_eq!(value.convert(ActionValueDim::Axis1D), 0.0.into());
+        assert!(!value.truncates(ActionValueDim::Bool));
+        assert!(!value.truncates(ActionValueDim::Axis2D));
+        assert!(!value.truncates(ActionValueDim::Axis3D));
+
+        let value = ActionValue::Axis3D((1.0, 0.0, 1.0).into());
+        assert!(value.truncates(ActionValueDim::Axis2D));
+        assert!(value.truncates(ActionValueDim::Axis1D));
+
+        let value = ActionValue::Axis2D((1.0, 0.0).into());
+        assert!(
+            !value.truncates(ActionValueDim::Axis1D),
+            "discarding zero axes should be allowed"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ActionValue::Bool(true).to_string(), "true");
+        assert_eq!(ActionValue::Bool(false).to_string(), "false");
+        assert_eq!(ActionValue::Axis1D(0.3).to_string(), "0.30");
+        assert_eq!(
+            ActionValue::Axis2D((0.3, -0.5).into()).to_string(),
+            "Vec2(0.30, -0.50)"
+        );
+        assert_eq!(
+            ActionValue::Axis3D((0.3, -0.5, 1.0).into()).to_string(),
+            "Vec3(0.30, -0.50, 1.00)"
+        );
+        assert_eq!(
+            format!("{:.1}", ActionValue::Axis2D((0.34, -0.5).into())),
+            "Vec2(0.3, -0.5)"
+        );
+    }
 }
@@ -0,0 +1,52 @@
+//! Diagnostics for input evaluation.
+//!
+//! Available only with the `diagnostics` feature.
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    prelude::*,
+};
+
+use crate::{input_context::ContextInstances, EnhancedInputSystem};
+
+/// Records per-frame statistics of [`EnhancedInputPlugin`](crate::EnhancedInputPlugin)
+/// into [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore).
+///
+/// Use [`LogDiagnosticsPlugin`](bevy::diagnostic::LogDiagnosticsPlugin)
+/// to print them to the console.
+pub struct InputDiagnosticsPlugin;
+
+impl Plugin for InputDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ACTIONS))
+            .register_diagnostic(Diagnostic::new(Self::BINDINGS))
+            .register_diagnostic(Diagnostic::new(Self::CONSUMED_INPUTS))
+            .register_diagnostic(Diagnostic::new(Self::EVENTS))
+            .add_systems(PreUpdate, Self::record.after(EnhancedInputSystem));
+    }
+}
+
+impl InputDiagnosticsPlugin {
+    /// Number of evaluated actions, including mocked ones.
+    pub const ACTIONS: DiagnosticPath = DiagnosticPath::const_new("enhanced_input/actions");
+
+    /// Number of read input bindings.
+    pub const BINDINGS: DiagnosticPath = DiagnosticPath::const_new("enhanced_input/bindings");
+
+    /// Number of inputs consumed by actions.
+    pub const CONSUMED_INPUTS: DiagnosticPath =
+        DiagnosticPath::const_new("enhanced_input/consumed_inputs");
+
+    /// Number of triggered action events.
+    ///
+    /// Events triggered on context removal or rebuild are not counted.
+    pub const EVENTS: DiagnosticPath = DiagnosticPath::const_new("enhanced_input/events");
+
+    fn record(mut diagnostics: Diagnostics, instances: Res<ContextInstances>) {
+        let stats = instances.stats();
+        diagnostics.add_measurement(&Self::ACTIONS, || stats.actions as f64);
+        diagnostics.add_measurement(&Self::BINDINGS, || stats.bindings as f64);
+        diagnostics.add_measurement(&Self::CONSUMED_INPUTS, || stats.consumed_inputs as f64);
+        diagnostics.add_measurement(&Self::EVENTS, || stats.events as f64);
+    }
+}
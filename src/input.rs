@@ -1,10 +1,16 @@
+pub mod gamepad_join;
 pub(super) mod input_reader;
 
-use std::hash::Hash;
+use std::{
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+};
 
-use bevy::prelude::*;
+use bevy::{input::mouse::MouseScrollUnit, prelude::*};
 use bitflags::bitflags;
-use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use crate::action_value::ActionValueDim;
 
 /// Inputs that can be associated with an
 /// [`InputAction`](super::input_context::input_action::InputAction).
@@ -13,29 +19,87 @@ use serde::{Deserialize, Serialize};
 ///
 /// If the action's dimension differs from the captured input, it will be converted using
 /// [`ActionValue::convert`](crate::action_value::ActionValue::convert).
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Input {
     /// Keyboard button, will be captured as
     /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
     Keyboard { key: KeyCode, mod_keys: ModKeys },
+    /// Keyboard button that is active only on the initial press and on each key repeat
+    /// generated by the OS, will be captured as
+    /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
+    ///
+    /// Useful for text-like actions, such as deleting characters.
+    ///
+    /// Repeat delay and rate are controlled by the user's OS settings and may differ between
+    /// platforms. Some platforms or backends may not report repeats at all, in which
+    /// case it behaves like [`JustPress`](crate::input_context::input_condition::just_press::JustPress).
+    KeyRepeat { key: KeyCode, mod_keys: ModKeys },
+    /// Character produced by a keyboard key according to the current layout, will be captured as
+    /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
+    ///
+    /// Unlike [`Self::Keyboard`], which refers to the physical key position, matches the
+    /// [`Key::Character`](bevy::input::keyboard::Key::Character) reported by the OS.
+    /// Useful for layout-independent bindings, such as `Z` for undo on both QWERTY and AZERTY.
+    /// The produced character also depends on held modifiers, for example, `Shift` turns `z` into `Z`.
+    ///
+    /// Logical keys can't be polled from [`ButtonInput`], so the character is tracked
+    /// from [`KeyboardInput`](bevy::input::keyboard::KeyboardInput) events and stays active
+    /// while the physical key that produced it is held.
+    /// Consuming the character also consumes the key that produced it, and vice versa.
+    Character { character: char, mod_keys: ModKeys },
     /// Mouse button, will be captured as
     /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
     MouseButton {
         button: MouseButton,
         mod_keys: ModKeys,
     },
+    /// Keyboard modifiers, will be captured as
+    /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
+    ///
+    /// Active when all modifiers are pressed, using either the left or the right key for each.
+    /// For example, [`ModKeys::SHIFT`] reacts to both [`KeyCode::ShiftLeft`] and [`KeyCode::ShiftRight`].
+    ModKeys(ModKeys),
     /// Mouse movement, will be captured as
     /// [`ActionValue::Axis2D`](crate::action_value::ActionValue::Axis2D).
     MouseMotion { mod_keys: ModKeys },
     /// Mouse wheel, will be captured as
     /// [`ActionValue::Axis1D`](crate::action_value::ActionValue::Axis1D).
+    ///
+    /// Units can be normalized with [`MouseScrollNormalization`].
     MouseWheel { mod_keys: ModKeys },
+    /// Cursor position inside a window, will be captured as
+    /// [`ActionValue::Axis2D`](crate::action_value::ActionValue::Axis2D).
+    ///
+    /// Reads the window with the specified entity or the
+    /// [`PrimaryWindow`](bevy::window::PrimaryWindow) if [`None`].
+    /// Captured as zero when the cursor is outside the window.
+    MousePosition { window: Option<Entity> },
     /// Gamepad button, will be captured as
     /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
     GamepadButton(GamepadButton),
     /// Gamepad stick axis, will be captured as
     /// [`ActionValue::Axis1D`](crate::action_value::ActionValue::Axis1D).
     GamepadAxis(GamepadAxis),
+    /// Analog value of a gamepad button, will be captured as
+    /// [`ActionValue::Axis1D`](crate::action_value::ActionValue::Axis1D).
+    ///
+    /// Unlike [`Self::GamepadButton`], which only reports whether the button is pressed,
+    /// outputs the unclamped value reported by the gamepad, such as how far
+    /// [`GamepadButton::RightTrigger2`] is pulled. Useful for throttle or brake.
+    ///
+    /// Shares consumption with [`Self::GamepadButton`] for the same button.
+    GamepadTrigger(GamepadButton),
+    /// Touch press, will be captured as
+    /// [`ActionValue::Bool`](crate::action_value::ActionValue::Bool).
+    ///
+    /// Reads the finger with the specified ID or any finger if [`None`].
+    TouchPress { finger: Option<u64> },
+    /// Touch movement since the last frame, will be captured as
+    /// [`ActionValue::Axis2D`](crate::action_value::ActionValue::Axis2D).
+    ///
+    /// Reads the finger with the specified ID or the finger with the lowest ID if [`None`].
+    TouchMotion { finger: Option<u64> },
 }
 
 impl Input {
@@ -55,17 +119,172 @@ impl Input {
         }
     }
 
+    /// Returns [`Input::MousePosition`] for the primary window.
+    #[must_use]
+    pub const fn mouse_position() -> Self {
+        Self::MousePosition { window: None }
+    }
+
+    /// Returns [`Input::TouchPress`] for any finger.
+    #[must_use]
+    pub const fn touch_press() -> Self {
+        Self::TouchPress { finger: None }
+    }
+
+    /// Returns [`Input::TouchMotion`] for the finger with the lowest ID.
+    #[must_use]
+    pub const fn touch_motion() -> Self {
+        Self::TouchMotion { finger: None }
+    }
+
+    /// Returns [`Input::GamepadTrigger`] to read the analog value of the button.
+    #[must_use]
+    pub const fn gamepad_trigger(button: GamepadButton) -> Self {
+        Self::GamepadTrigger(button)
+    }
+
+    /// Returns [`Input::KeyRepeat`] without keyboard modifiers.
+    #[must_use]
+    pub const fn key_repeat(key: KeyCode) -> Self {
+        Self::KeyRepeat {
+            key,
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
+    /// Returns [`Input::Character`] without keyboard modifiers.
+    #[must_use]
+    pub const fn character(character: char) -> Self {
+        Self::Character {
+            character,
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
+    /// Returns `true` if both inputs use the same physical key or button, ignoring keyboard modifiers.
+    ///
+    /// Useful to detect overlaps during rebinding, such as `Ctrl + S` and `S`.
+    /// [`Self::Keyboard`] and [`Self::KeyRepeat`] with the same key are also considered the same,
+    /// as well as [`Self::GamepadButton`] and [`Self::GamepadTrigger`] with the same button.
+    pub fn same_key(self, other: impl Into<Input>) -> bool {
+        match (self, other.into()) {
+            (
+                Input::Keyboard { key, .. } | Input::KeyRepeat { key, .. },
+                Input::Keyboard { key: other, .. } | Input::KeyRepeat { key: other, .. },
+            ) => key == other,
+            (
+                Input::Character { character, .. },
+                Input::Character {
+                    character: other, ..
+                },
+            ) => character == other,
+            (Input::MouseButton { button, .. }, Input::MouseButton { button: other, .. }) => {
+                button == other
+            }
+            (
+                Input::GamepadButton(button) | Input::GamepadTrigger(button),
+                Input::GamepadButton(other) | Input::GamepadTrigger(other),
+            ) => button == other,
+            (Input::MouseMotion { .. }, Input::MouseMotion { .. })
+            | (Input::MouseWheel { .. }, Input::MouseWheel { .. }) => true,
+            (input, other) => input == other,
+        }
+    }
+
+    /// Returns `true` if both inputs use the same physical key or button and this input
+    /// requires all keyboard modifiers of the other one plus at least one more.
+    ///
+    /// For example, `Ctrl + S` extends `S`, but not `Alt + S`.
+    pub fn extends_mod_keys(self, other: impl Into<Input>) -> bool {
+        let other = other.into();
+        let (mod_keys, other_mod_keys) = (self.mod_keys(), other.mod_keys());
+        self.same_key(other) && mod_keys != other_mod_keys && mod_keys.contains(other_mod_keys)
+    }
+
+    /// Returns keyboard modifiers of the input.
+    ///
+    /// Returns [`ModKeys::empty`] for inputs that don't support modifiers.
+    pub fn mod_keys(self) -> ModKeys {
+        match self {
+            Input::Keyboard { mod_keys, .. }
+            | Input::KeyRepeat { mod_keys, .. }
+            | Input::Character { mod_keys, .. }
+            | Input::MouseButton { mod_keys, .. }
+            | Input::MouseMotion { mod_keys }
+            | Input::MouseWheel { mod_keys } => mod_keys,
+            Input::ModKeys(_)
+            | Input::MousePosition { .. }
+            | Input::GamepadButton(_)
+            | Input::GamepadAxis(_)
+            | Input::GamepadTrigger(_)
+            | Input::TouchPress { .. }
+            | Input::TouchMotion { .. } => ModKeys::empty(),
+        }
+    }
+
+    /// Returns the dimension of the captured value.
+    pub fn dim(self) -> ActionValueDim {
+        match self {
+            Input::Keyboard { .. }
+            | Input::KeyRepeat { .. }
+            | Input::Character { .. }
+            | Input::MouseButton { .. }
+            | Input::ModKeys(_)
+            | Input::GamepadButton(_)
+            | Input::TouchPress { .. } => ActionValueDim::Bool,
+            Input::GamepadAxis(_) | Input::GamepadTrigger(_) => ActionValueDim::Axis1D,
+            Input::MouseMotion { .. }
+            | Input::MouseWheel { .. }
+            | Input::MousePosition { .. }
+            | Input::TouchMotion { .. } => ActionValueDim::Axis2D,
+        }
+    }
+
     /// Returns new instance without any keyboard modifiers.
     ///
     /// # Panics
     ///
-    /// Panics when called on [`Self::GamepadButton`] or [`Self::GamepadAxis`].
+    /// Panics when called on gamepad, touch, mouse position or [`Self::ModKeys`] inputs.
     #[must_use]
     pub fn without_mod_keys(self) -> Self {
         self.with_mod_keys(ModKeys::empty())
     }
 }
 
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Input::Keyboard { key, mod_keys } => write!(f, "{mod_keys}{key:?}"),
+            Input::KeyRepeat { key, mod_keys } => write!(f, "{mod_keys}{key:?} (Repeat)"),
+            Input::Character {
+                character,
+                mod_keys,
+            } => write!(f, "{mod_keys}'{character}'"),
+            Input::MouseButton { button, mod_keys } => write!(f, "{mod_keys}Mouse {button:?}"),
+            Input::ModKeys(mod_keys) => {
+                write!(f, "{}", mod_keys.to_string().trim_end_matches(" + "))
+            }
+            Input::MouseMotion { mod_keys } => write!(f, "{mod_keys}Mouse Motion"),
+            Input::MouseWheel { mod_keys } => write!(f, "{mod_keys}Mouse Wheel"),
+            Input::MousePosition { window: None } => write!(f, "Mouse Position"),
+            Input::MousePosition {
+                window: Some(window),
+            } => write!(f, "Mouse Position ({window})"),
+            Input::GamepadButton(button) => write!(f, "Gamepad {button:?}"),
+            Input::GamepadAxis(axis) => write!(f, "Gamepad {axis:?}"),
+            Input::GamepadTrigger(button) => write!(f, "Gamepad {button:?} (Analog)"),
+            Input::TouchPress { finger: None } => write!(f, "Touch"),
+            Input::TouchPress {
+                finger: Some(finger),
+            } => write!(f, "Touch {finger}"),
+            Input::TouchMotion { finger: None } => write!(f, "Touch Motion"),
+            Input::TouchMotion {
+                finger: Some(finger),
+            } => write!(f, "Touch {finger} Motion"),
+        }
+    }
+}
+
 impl From<KeyCode> for Input {
     fn from(key: KeyCode) -> Self {
         Self::Keyboard {
@@ -75,6 +294,15 @@ impl From<KeyCode> for Input {
     }
 }
 
+impl From<char> for Input {
+    fn from(character: char) -> Self {
+        Self::Character {
+            character,
+            mod_keys: Default::default(),
+        }
+    }
+}
+
 impl From<MouseButton> for Input {
     fn from(button: MouseButton) -> Self {
         Self::MouseButton {
@@ -84,6 +312,12 @@ impl From<MouseButton> for Input {
     }
 }
 
+impl From<ModKeys> for Input {
+    fn from(value: ModKeys) -> Self {
+        Self::ModKeys(value)
+    }
+}
+
 impl From<GamepadButton> for Input {
     fn from(value: GamepadButton) -> Self {
         Self::GamepadButton(value)
@@ -108,23 +342,38 @@ impl<I: Into<Input>> InputModKeys for I {
     ///
     /// # Panics
     ///
-    /// Panics when called on [`Input::GamepadButton`] or [`Input::GamepadAxis`].
+    /// Panics when called on gamepad, touch, mouse position or [`Input::ModKeys`] inputs.
     fn with_mod_keys(self, mod_keys: ModKeys) -> Input {
         match self.into() {
             Input::Keyboard { key, .. } => Input::Keyboard { key, mod_keys },
+            Input::KeyRepeat { key, .. } => Input::KeyRepeat { key, mod_keys },
+            Input::Character { character, .. } => Input::Character {
+                character,
+                mod_keys,
+            },
             Input::MouseButton { button, .. } => Input::MouseButton { button, mod_keys },
             Input::MouseMotion { .. } => Input::MouseMotion { mod_keys },
             Input::MouseWheel { .. } => Input::MouseWheel { mod_keys },
-            Input::GamepadButton { .. } | Input::GamepadAxis { .. } => {
+            Input::ModKeys(_) => {
+                panic!("keyboard modifiers can't be applied to keyboard modifiers")
+            }
+            Input::MousePosition { .. } => {
+                panic!("keyboard modifiers can't be applied to mouse position")
+            }
+            Input::GamepadButton(_) | Input::GamepadAxis(_) | Input::GamepadTrigger(_) => {
                 panic!("keyboard modifiers can't be applied to gamepads")
             }
+            Input::TouchPress { .. } | Input::TouchMotion { .. } => {
+                panic!("keyboard modifiers can't be applied to touches")
+            }
         }
     }
 }
 
 bitflags! {
     /// Keyboard modifiers for both left and right keys.
-    #[derive(Default, Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct ModKeys: u8 {
         /// Corresponds to [`KeyCode::AltLeft`] and [`KeyCode::AltRight`].
         const ALT = 0b00000001;
@@ -152,6 +401,24 @@ impl ModKeys {
     }
 }
 
+impl Display for ModKeys {
+    /// Writes each modifier followed by ` + `, so it can be used as a prefix for the key.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (_, mod_key) in self.iter_names() {
+            let name = match mod_key {
+                ModKeys::ALT => "Alt",
+                ModKeys::CONTROL => "Ctrl",
+                ModKeys::SHIFT => "Shift",
+                ModKeys::SUPER => "Super",
+                _ => unreachable!("iteration should yield only named flags"),
+            };
+            write!(f, "{name} + ")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl From<KeyCode> for ModKeys {
     /// Converts key into a named modifier
     ///
@@ -168,26 +435,55 @@ impl From<KeyCode> for ModKeys {
 }
 
 /// Associated gamepad.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, Default, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GamepadDevice {
     /// Matches input from any gamepad.
     ///
-    /// For an axis, the [`ActionValue`] will be calculated as the sum of inputs from all gamepads.
+    /// For an axis or [`Input::GamepadTrigger`], the [`ActionValue`] will be calculated
+    /// from inputs of all gamepads according to [`AnyGamepadReduce`].
     /// For a button, the [`ActionValue`] will be `true` if any gamepad has this button pressed.
     ///
     /// [`ActionValue`]: crate::action_value::ActionValue
     #[default]
     Any,
+    /// Matches input from any gamepad, but reads each axis from the gamepad
+    /// with the largest magnitude on it.
+    ///
+    /// Unlike [`Self::Any`], ignores [`AnyGamepadReduce`]. This avoids stick drift from idle
+    /// gamepads leaking into the value when [`AnyGamepadReduce::Sum`] is used globally,
+    /// but a small movement on one gamepad is hidden while another one is pushed further.
+    /// Axes are selected independently, so X and Y of a stick may come from different gamepads.
+    ///
+    /// Buttons behave like in [`Self::Any`], while analog values
+    /// of [`Input::GamepadTrigger`] are selected like axes.
+    AnyBest,
     /// Matches input from specific gamepad.
     Single(Entity),
+    /// Matches input from the listed gamepads.
+    ///
+    /// For an axis, the [`ActionValue`] will be the sum of values from these gamepads.
+    /// For a button, the [`ActionValue`] will be `true` if any of these gamepads has this button pressed.
+    ///
+    /// Useful when a single player uses multiple devices, such as a wheel with pedals.
+    ///
+    /// [`ActionValue`]: crate::action_value::ActionValue
+    Set(SmallVec<[Entity; 4]>),
+    /// Ignores input from all gamepads.
+    ///
+    /// Useful for contexts that should be controlled only by keyboard and mouse,
+    /// such as a keyboard player in local multiplayer.
+    None,
 }
 
 impl GamepadDevice {
     /// Returns `true` if this device matches the specified gamepad entity.
-    pub fn matches(self, gamepad_entity: Entity) -> bool {
+    pub fn matches(&self, gamepad_entity: Entity) -> bool {
         match self {
-            GamepadDevice::Any => true,
-            GamepadDevice::Single(entity) => entity == gamepad_entity,
+            GamepadDevice::Any | GamepadDevice::AnyBest => true,
+            GamepadDevice::Single(entity) => *entity == gamepad_entity,
+            GamepadDevice::Set(entities) => entities.contains(&gamepad_entity),
+            GamepadDevice::None => false,
         }
     }
 }
@@ -197,3 +493,195 @@ impl From<Entity> for GamepadDevice {
         Self::Single(value)
     }
 }
+
+impl<const N: usize> From<[Entity; N]> for GamepadDevice {
+    fn from(value: [Entity; N]) -> Self {
+        Self::Set(value.into_iter().collect())
+    }
+}
+
+/// Defines how axis values from multiple gamepads are combined for [`GamepadDevice::Any`].
+///
+/// Resolved once per frame in [`EnhancedInputSystem`](crate::EnhancedInputSystem).
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnyGamepadReduce {
+    /// Takes the first non-zero value.
+    ///
+    /// Gamepads are checked in their query order, which is not guaranteed to be stable.
+    #[default]
+    First,
+    /// Sums values from all gamepads.
+    ///
+    /// Small stick drift from idle gamepads will stack up.
+    Sum,
+    /// Takes the value with the largest magnitude.
+    Max,
+}
+
+impl AnyGamepadReduce {
+    /// Combines the accumulated value with a value from the next gamepad.
+    fn reduce(self, accumulated: f32, value: f32) -> f32 {
+        match self {
+            AnyGamepadReduce::First => accumulated,
+            AnyGamepadReduce::Sum => accumulated + value,
+            AnyGamepadReduce::Max if value.abs() > accumulated.abs() => value,
+            AnyGamepadReduce::Max => accumulated,
+        }
+    }
+}
+
+/// Defines how [`Input::MouseWheel`] values with different [`MouseScrollUnit`]s are normalized.
+///
+/// Mouse wheels usually report scroll in lines, while trackpads report it in pixels,
+/// so the same gesture can produce values that differ by an order of magnitude.
+///
+/// By default, values are passed as is.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseScrollNormalization {
+    /// Uses reported values regardless of their unit.
+    #[default]
+    None,
+    /// Converts pixel values into lines by dividing them by the specified number of pixels per line.
+    Lines { pixels_per_line: f32 },
+    /// Converts line values into pixels by multiplying them by the specified number of pixels per line.
+    Pixels { pixels_per_line: f32 },
+}
+
+impl MouseScrollNormalization {
+    /// Number of pixels in a single scroll line used by [`Self::lines`] and [`Self::pixels`].
+    pub const DEFAULT_PIXELS_PER_LINE: f32 = 16.0;
+
+    /// Returns [`Self::Lines`] with [`Self::DEFAULT_PIXELS_PER_LINE`].
+    #[must_use]
+    pub const fn lines() -> Self {
+        Self::Lines {
+            pixels_per_line: Self::DEFAULT_PIXELS_PER_LINE,
+        }
+    }
+
+    /// Returns [`Self::Pixels`] with [`Self::DEFAULT_PIXELS_PER_LINE`].
+    #[must_use]
+    pub const fn pixels() -> Self {
+        Self::Pixels {
+            pixels_per_line: Self::DEFAULT_PIXELS_PER_LINE,
+        }
+    }
+
+    /// Converts the scroll delta according to its unit.
+    fn normalize(self, delta: Vec2, unit: MouseScrollUnit) -> Vec2 {
+        match (self, unit) {
+            (Self::Lines { pixels_per_line }, MouseScrollUnit::Pixel) => delta / pixels_per_line,
+            (Self::Pixels { pixels_per_line }, MouseScrollUnit::Line) => delta * pixels_per_line,
+            _ => delta,
+        }
+    }
+}
+
+/// Indicates whether any keyboard, mouse or gamepad input is active during this frame.
+///
+/// Held buttons also count as active. Input captured by UI is ignored
+/// if `ui_priority` or `egui_priority` feature is enabled.
+///
+/// Updated in [`EnhancedInputSystem`](crate::EnhancedInputSystem) before evaluating contexts.
+/// Useful for "press any button" screens or idle timeouts.
+#[derive(Resource, Default, Debug, Clone, Copy, Deref)]
+pub struct AnyInput(pub(crate) bool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_key() {
+        let input = Input::from(KeyCode::KeyS);
+        assert!(input.same_key(KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)));
+        assert!(input.same_key(Input::key_repeat(KeyCode::KeyS)));
+        assert!(!input.same_key(KeyCode::KeyD));
+        assert!(!input.same_key(MouseButton::Left));
+        assert!(!input.same_key('s'));
+
+        let input = Input::character('z');
+        assert!(input.same_key('z'.with_mod_keys(ModKeys::CONTROL)));
+        assert!(!input.same_key('y'));
+
+        let input = MouseButton::Left.with_mod_keys(ModKeys::SHIFT);
+        assert!(input.same_key(MouseButton::Left));
+        assert!(!input.same_key(MouseButton::Right));
+
+        let input = Input::mouse_wheel();
+        assert!(input.same_key(Input::mouse_wheel().with_mod_keys(ModKeys::ALT)));
+        assert!(!input.same_key(Input::mouse_motion()));
+
+        let input = Input::from(GamepadButton::South);
+        assert!(input.same_key(GamepadButton::South));
+        assert!(!input.same_key(GamepadButton::North));
+        assert!(input.same_key(Input::gamepad_trigger(GamepadButton::South)));
+    }
+
+    #[test]
+    fn extends_mod_keys() {
+        let input = KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL | ModKeys::SHIFT);
+        assert!(input.extends_mod_keys(KeyCode::KeyS));
+        assert!(input.extends_mod_keys(KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)));
+        assert!(!input.extends_mod_keys(input));
+        assert!(!input.extends_mod_keys(KeyCode::KeyS.with_mod_keys(ModKeys::ALT)));
+        assert!(!input.extends_mod_keys(KeyCode::KeyD));
+        assert!(!Input::from(GamepadButton::South).extends_mod_keys(GamepadButton::South));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn gamepad_set_serialization() {
+        let gamepad = GamepadDevice::from([Entity::from_raw(1), Entity::from_raw(2)]);
+        let serialized = ron::to_string(&gamepad).unwrap();
+        let deserialized: GamepadDevice = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, gamepad);
+        assert!(deserialized.matches(Entity::from_raw(2)));
+        assert!(!deserialized.matches(Entity::from_raw(3)));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Input::from(KeyCode::KeyA).to_string(), "KeyA");
+        assert_eq!(
+            KeyCode::KeyS
+                .with_mod_keys(ModKeys::CONTROL | ModKeys::SHIFT)
+                .to_string(),
+            "Ctrl + Shift + KeyS"
+        );
+        assert_eq!(
+            Input::key_repeat(KeyCode::Backspace).to_string(),
+            "Backspace (Repeat)"
+        );
+        assert_eq!(
+            'z'.with_mod_keys(ModKeys::CONTROL).to_string(),
+            "Ctrl + 'z'"
+        );
+        assert_eq!(Input::from(MouseButton::Left).to_string(), "Mouse Left");
+        assert_eq!(
+            Input::from(ModKeys::CONTROL | ModKeys::SHIFT).to_string(),
+            "Ctrl + Shift"
+        );
+        assert_eq!(
+            Input::mouse_motion()
+                .with_mod_keys(ModKeys::ALT)
+                .to_string(),
+            "Alt + Mouse Motion"
+        );
+        assert_eq!(Input::mouse_wheel().to_string(), "Mouse Wheel");
+        assert_eq!(Input::mouse_position().to_string(), "Mouse Position");
+        assert_eq!(
+            Input::from(GamepadButton::South).to_string(),
+            "Gamepad South"
+        );
+        assert_eq!(
+            Input::from(GamepadAxis::LeftStickX).to_string(),
+            "Gamepad LeftStickX"
+        );
+        assert_eq!(
+            Input::gamepad_trigger(GamepadButton::RightTrigger2).to_string(),
+            "Gamepad RightTrigger2 (Analog)"
+        );
+    }
+}
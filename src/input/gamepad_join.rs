@@ -0,0 +1,141 @@
+use bevy::{input::InputSystem, prelude::*, utils::HashSet};
+
+/// Emits [`PlayerJoined`] when a gamepad that isn't assigned to a player presses the join button.
+///
+/// Useful for "press start to join" in local multiplayer. The plugin only tracks
+/// assignments; spawn a player context for the gamepad in response to the event
+/// and pass the gamepad entity to [`ContextInstance::set_gamepad`](crate::input_context::context_instance::ContextInstance::set_gamepad).
+///
+/// Gamepads are assigned automatically on join and unassigned on disconnect.
+/// Use [`GamepadJoin`] to manage assignments manually, for example when a player leaves.
+///
+/// Not added by [`EnhancedInputPlugin`](crate::EnhancedInputPlugin).
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(GamepadJoinPlugin::default())
+///     .add_systems(Update, spawn_players);
+///
+/// fn spawn_players(mut commands: Commands, mut join_events: EventReader<PlayerJoined>) {
+///     for event in join_events.read() {
+///         commands.spawn(Player(event.gamepad));
+///     }
+/// }
+///
+/// #[derive(Component, Deref)]
+/// struct Player(Entity);
+///
+/// impl InputContext for Player {
+///     fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+///         let mut ctx = ContextInstance::default();
+///
+///         let gamepad_entity = **world.get::<Self>(entity).unwrap();
+///         ctx.set_gamepad(gamepad_entity);
+///         ctx.bind::<Jump>().to(GamepadButton::South);
+///
+///         ctx
+///     }
+/// }
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Jump;
+/// ```
+pub struct GamepadJoinPlugin {
+    /// Button that assigns the gamepad when pressed.
+    ///
+    /// By default set to [`GamepadButton::Start`].
+    pub join_button: GamepadButton,
+}
+
+impl Default for GamepadJoinPlugin {
+    fn default() -> Self {
+        Self {
+            join_button: GamepadButton::Start,
+        }
+    }
+}
+
+impl Plugin for GamepadJoinPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlayerJoined>()
+            .insert_resource(GamepadJoin {
+                join_button: self.join_button,
+                assigned: Default::default(),
+            })
+            .add_systems(
+                PreUpdate,
+                (Self::unassign_disconnected, Self::join)
+                    .chain()
+                    .after(InputSystem),
+            );
+    }
+}
+
+impl GamepadJoinPlugin {
+    fn unassign_disconnected(
+        mut removed_gamepads: RemovedComponents<Gamepad>,
+        mut join: ResMut<GamepadJoin>,
+    ) {
+        for gamepad_entity in removed_gamepads.read() {
+            join.unassign(gamepad_entity);
+        }
+    }
+
+    fn join(
+        mut join_events: EventWriter<PlayerJoined>,
+        mut join: ResMut<GamepadJoin>,
+        gamepads: Query<(Entity, &Gamepad)>,
+    ) {
+        for (gamepad_entity, gamepad) in &gamepads {
+            if gamepad.pressed(join.join_button) && join.assign(gamepad_entity) {
+                debug!("gamepad `{gamepad_entity}` joined");
+                join_events.send(PlayerJoined {
+                    gamepad: gamepad_entity,
+                });
+            }
+        }
+    }
+}
+
+/// Gamepad assignments for [`GamepadJoinPlugin`].
+#[derive(Resource)]
+pub struct GamepadJoin {
+    /// Button that assigns the gamepad when pressed.
+    pub join_button: GamepadButton,
+
+    assigned: HashSet<Entity>,
+}
+
+impl GamepadJoin {
+    /// Marks the gamepad as assigned to a player.
+    ///
+    /// Returns `true` if it wasn't assigned before.
+    pub fn assign(&mut self, gamepad_entity: Entity) -> bool {
+        self.assigned.insert(gamepad_entity)
+    }
+
+    /// Makes the gamepad available to join again.
+    ///
+    /// Returns `true` if it was assigned.
+    pub fn unassign(&mut self, gamepad_entity: Entity) -> bool {
+        self.assigned.remove(&gamepad_entity)
+    }
+
+    /// Returns `true` if the gamepad is assigned to a player.
+    pub fn is_assigned(&self, gamepad_entity: Entity) -> bool {
+        self.assigned.contains(&gamepad_entity)
+    }
+}
+
+/// Emitted by [`GamepadJoinPlugin`] when an unassigned gamepad presses the join button.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerJoined {
+    /// Entity of the joined gamepad.
+    pub gamepad: Entity,
+}
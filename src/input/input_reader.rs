@@ -2,27 +2,46 @@ use std::hash::Hash;
 
 use bevy::{
     ecs::system::SystemParam,
-    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
+    input::{
+        keyboard::{Key, KeyboardInput},
+        mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
+    },
     prelude::*,
-    utils::HashSet,
+    utils::{HashMap, HashSet},
+    window::PrimaryWindow,
 };
 #[cfg(feature = "egui_priority")]
 use bevy_egui::EguiContext;
 
-use super::{GamepadDevice, Input, ModKeys};
+use super::{AnyGamepadReduce, GamepadDevice, Input, ModKeys, MouseScrollNormalization};
 use crate::action_value::ActionValue;
 
 /// Reads input from multiple sources.
 #[derive(SystemParam)]
 pub(crate) struct InputReader<'w, 's> {
     keys: Res<'w, ButtonInput<KeyCode>>,
+    keyboard_events: EventReader<'w, 's, KeyboardInput>,
+    repeated_keys: Local<'s, HashSet<KeyCode>>,
+    /// Characters produced by currently held keys.
+    ///
+    /// Logical keys are not available in [`ButtonInput`], so they are accumulated from events.
+    pressed_characters: Local<'s, HashMap<KeyCode, char>>,
     mouse_buttons: Res<'w, ButtonInput<MouseButton>>,
     mouse_motion: Res<'w, AccumulatedMouseMotion>,
     mouse_scroll: Res<'w, AccumulatedMouseScroll>,
+    scroll_normalization: Res<'w, MouseScrollNormalization>,
+    /// Windows to read the cursor position for [`Input::MousePosition`].
+    windows: Query<'w, 's, (&'static Window, Has<PrimaryWindow>)>,
+    touches: Res<'w, Touches>,
+    touch_deltas: Local<'s, TouchDeltaCache>,
     gamepads: Query<'w, 's, &'static Gamepad>,
+    any_gamepad_reduce: Res<'w, AnyGamepadReduce>,
     consumed: Local<'s, ConsumedInput>,
     reset_input: ResMut<'w, ResetInput>,
     gamepad_device: Local<'s, GamepadDevice>,
+    ignore_keyboard_mouse: Local<'s, bool>,
+    ignore_touch: Local<'s, bool>,
+    any_gamepad: Local<'s, AnyGamepadCache>,
     #[cfg(feature = "ui_priority")]
     interactions: Query<'w, 's, &'static Interaction>,
     // In egui mutable reference is required to get contexts,
@@ -32,9 +51,36 @@ pub(crate) struct InputReader<'w, 's> {
 }
 
 impl InputReader<'_, '_> {
-    /// Resets all consumed values and reads mouse events.
+    /// Resets all consumed values, reads key repeats and characters and caches input from all gamepads.
     pub(crate) fn update_state(&mut self) {
         self.consumed.reset();
+        self.set_keyboard_mouse(true);
+        self.set_touch(true);
+        self.any_gamepad
+            .update(&self.gamepads, *self.any_gamepad_reduce);
+        self.touch_deltas.update(&self.touches);
+
+        self.repeated_keys.clear();
+        for event in self.keyboard_events.read() {
+            if !event.state.is_pressed() {
+                self.pressed_characters.remove(&event.key_code);
+                continue;
+            }
+
+            if event.repeat {
+                self.repeated_keys.insert(event.key_code);
+            }
+
+            if let Key::Character(text) = &event.logical_key {
+                let mut chars = text.chars();
+                if let (Some(character), None) = (chars.next(), chars.next()) {
+                    self.pressed_characters.insert(event.key_code, character);
+                }
+            }
+        }
+        // Release events may be missed, for example, when the window loses focus.
+        let keys = &self.keys;
+        self.pressed_characters.retain(|&key, _| keys.pressed(key));
 
         // Temporary take the original value to avoid issues with the borrow checker.
         let mut reset_input = std::mem::take(&mut *self.reset_input);
@@ -76,35 +122,105 @@ impl InputReader<'_, '_> {
         }
     }
 
+    /// Returns `true` if any input is active, excluding input captured by UI.
+    ///
+    /// Doesn't take into account consumed input and assigned devices.
+    pub(crate) fn any_input(&self) -> bool {
+        let keyboard = !self.consumed.ui_wants_keyboard && self.keys.get_pressed().next().is_some();
+
+        let mouse = !self.consumed.ui_wants_mouse
+            && (self.mouse_buttons.get_pressed().next().is_some()
+                || self.mouse_motion.delta != Vec2::ZERO
+                || self.mouse_scroll.delta != Vec2::ZERO);
+
+        let gamepad = !self.any_gamepad.buttons.is_empty() || !self.any_gamepad.axes.is_empty();
+
+        let touch = !self.consumed.ui_wants_mouse && self.touches.iter().next().is_some();
+
+        keyboard || mouse || gamepad || touch
+    }
+
+    /// Returns `true` if any window is focused or if there are no windows.
+    pub(crate) fn window_focused(&self) -> bool {
+        self.windows.is_empty() || self.windows.iter().any(|(window, _)| window.focused)
+    }
+
     /// Assigns a gamepad from which [`Self::value`] should read input.
     pub(crate) fn set_gamepad(&mut self, gamepad: impl Into<GamepadDevice>) {
         *self.gamepad_device = gamepad.into();
     }
 
+    /// Enables or disables reading from keyboard and mouse for [`Self::value`].
+    pub(crate) fn set_keyboard_mouse(&mut self, enabled: bool) {
+        *self.ignore_keyboard_mouse = !enabled;
+    }
+
+    /// Enables or disables reading from touch for [`Self::value`].
+    pub(crate) fn set_touch(&mut self, enabled: bool) {
+        *self.ignore_touch = !enabled;
+    }
+
     /// Returns the [`ActionValue`] for the given [`Input`] if exists.
     ///
-    /// See also [`Self::consume`] and [`Self::set_gamepad`].
+    /// See also [`Self::consume`], [`Self::set_gamepad`], [`Self::set_keyboard_mouse`]
+    /// and [`Self::set_touch`].
     pub(crate) fn value(&self, input: impl Into<Input>) -> ActionValue {
-        match input.into() {
+        self.read(input.into(), false)
+    }
+
+    /// Like [`Self::value`], but ignores the input if any keyboard modifiers
+    /// other than the specified ones are pressed.
+    pub(crate) fn exact_value(&self, input: impl Into<Input>) -> ActionValue {
+        self.read(input.into(), true)
+    }
+
+    fn read(&self, input: Input, exact_mod_keys: bool) -> ActionValue {
+        match input {
             Input::Keyboard { key, mod_keys } => {
-                let pressed = !self.consumed.ui_wants_keyboard
+                let pressed = !self.keyboard_blocked()
                     && self.keys.pressed(key)
                     && !self.consumed.keys.contains(&key)
-                    && self.mod_keys_pressed(mod_keys);
+                    && self.mod_keys_pressed_ignoring(mod_keys, key.into(), exact_mod_keys);
+
+                pressed.into()
+            }
+            Input::KeyRepeat { key, mod_keys } => {
+                let pressed = !self.keyboard_blocked()
+                    && (self.keys.just_pressed(key) || self.repeated_keys.contains(&key))
+                    && !self.consumed.keys.contains(&key)
+                    && self.mod_keys_pressed_ignoring(mod_keys, key.into(), exact_mod_keys);
+
+                pressed.into()
+            }
+            Input::Character {
+                character,
+                mod_keys,
+            } => {
+                let pressed = !self.keyboard_blocked()
+                    && self.pressed_characters.values().any(|&c| c == character)
+                    && !self.consumed.characters.contains(&character)
+                    && self.mod_keys_pressed(mod_keys, exact_mod_keys);
 
                 pressed.into()
             }
             Input::MouseButton { button, mod_keys } => {
-                let pressed = !self.consumed.ui_wants_mouse
+                let pressed = !self.mouse_blocked()
                     && self.mouse_buttons.pressed(button)
                     && !self.consumed.mouse_buttons.contains(&button)
-                    && self.mod_keys_pressed(mod_keys);
+                    && self.mod_keys_pressed(mod_keys, exact_mod_keys);
+
+                pressed.into()
+            }
+            Input::ModKeys(mod_keys) => {
+                let pressed = !mod_keys.is_empty()
+                    && !self.keyboard_blocked()
+                    && self.mod_keys_pressed(mod_keys, exact_mod_keys);
 
                 pressed.into()
             }
             Input::MouseMotion { mod_keys } => {
-                if self.consumed.ui_wants_mouse
-                    || !self.mod_keys_pressed(mod_keys)
+                if self.mouse_blocked()
+                    || !self.mod_keys_pressed(mod_keys, exact_mod_keys)
                     || self.consumed.mouse_motion
                 {
                     return Vec2::ZERO.into();
@@ -113,18 +229,35 @@ impl InputReader<'_, '_> {
                 self.mouse_motion.delta.into()
             }
             Input::MouseWheel { mod_keys } => {
-                if self.consumed.ui_wants_mouse
-                    || !self.mod_keys_pressed(mod_keys)
+                if self.mouse_blocked()
+                    || !self.mod_keys_pressed(mod_keys, exact_mod_keys)
                     || self.consumed.mouse_wheel
                 {
                     return Vec2::ZERO.into();
                 }
 
-                self.mouse_scroll.delta.into()
+                self.scroll_normalization
+                    .normalize(self.mouse_scroll.delta, self.mouse_scroll.unit)
+                    .into()
+            }
+            Input::MousePosition { window } => {
+                if self.mouse_blocked() || self.consumed.mouse_position {
+                    return Vec2::ZERO.into();
+                }
+
+                let window = match window {
+                    Some(entity) => self.windows.get(entity).ok(),
+                    None => self.windows.iter().find(|&(_, primary)| primary),
+                };
+
+                window
+                    .and_then(|(window, _)| window.cursor_position())
+                    .unwrap_or_default()
+                    .into()
             }
             Input::GamepadButton(button) => {
                 let input = GamepadInput {
-                    gamepad: *self.gamepad_device,
+                    gamepad: self.gamepad_device.clone(),
                     input: button,
                 };
 
@@ -132,21 +265,26 @@ impl InputReader<'_, '_> {
                     return false.into();
                 }
 
-                let pressed = match *self.gamepad_device {
-                    GamepadDevice::Any => {
-                        self.gamepads.iter().any(|gamepad| gamepad.pressed(button))
+                let pressed = match &*self.gamepad_device {
+                    GamepadDevice::Any | GamepadDevice::AnyBest => {
+                        self.any_gamepad.buttons.contains(&button)
                     }
-                    GamepadDevice::Single(entity) => self
+                    GamepadDevice::None => false,
+                    &GamepadDevice::Single(entity) => self
                         .gamepads
                         .get(entity)
                         .is_ok_and(|gamepad| gamepad.pressed(button)),
+                    GamepadDevice::Set(entities) => self
+                        .gamepads
+                        .iter_many(entities)
+                        .any(|gamepad| gamepad.pressed(button)),
                 };
 
                 pressed.into()
             }
             Input::GamepadAxis(axis) => {
                 let input = GamepadInput {
-                    gamepad: *self.gamepad_device,
+                    gamepad: self.gamepad_device.clone(),
                     input: axis,
                 };
 
@@ -154,25 +292,130 @@ impl InputReader<'_, '_> {
                     return 0.0.into();
                 }
 
-                let value = match *self.gamepad_device {
-                    GamepadDevice::Any => self.gamepads.iter().find_map(|gamepad| {
-                        gamepad.get_unclamped(axis).filter(|&value| value != 0.0)
-                    }),
-                    GamepadDevice::Single(entity) => self
+                let value = match &*self.gamepad_device {
+                    GamepadDevice::Any => self.any_gamepad.axes.get(&axis).copied(),
+                    GamepadDevice::AnyBest => self.any_gamepad.best_axes.get(&axis).copied(),
+                    GamepadDevice::None => None,
+                    &GamepadDevice::Single(entity) => self
                         .gamepads
                         .get(entity)
                         .ok()
                         .and_then(|gamepad| gamepad.get(axis)),
+                    GamepadDevice::Set(entities) => Some(
+                        self.gamepads
+                            .iter_many(entities)
+                            .filter_map(|gamepad| gamepad.get(axis))
+                            .sum(),
+                    ),
+                };
+
+                let value = value.unwrap_or_default();
+                value.into()
+            }
+            Input::GamepadTrigger(button) => {
+                let input = GamepadInput {
+                    gamepad: self.gamepad_device.clone(),
+                    input: button,
+                };
+
+                if self.consumed.gamepad_buttons.contains(&input) {
+                    return 0.0.into();
+                }
+
+                let value = match &*self.gamepad_device {
+                    GamepadDevice::Any => self.any_gamepad.triggers.get(&button).copied(),
+                    GamepadDevice::AnyBest => self.any_gamepad.best_triggers.get(&button).copied(),
+                    GamepadDevice::None => None,
+                    &GamepadDevice::Single(entity) => self
+                        .gamepads
+                        .get(entity)
+                        .ok()
+                        .and_then(|gamepad| gamepad.get(button)),
+                    GamepadDevice::Set(entities) => Some(
+                        self.gamepads
+                            .iter_many(entities)
+                            .filter_map(|gamepad| gamepad.get(button))
+                            .sum(),
+                    ),
                 };
 
                 let value = value.unwrap_or_default();
                 value.into()
             }
+            Input::TouchPress { finger } => {
+                let pressed = !self.touch_blocked()
+                    && match finger {
+                        Some(finger) => {
+                            self.touches.get_pressed(finger).is_some()
+                                && !self.consumed.touch_presses.contains(&finger)
+                        }
+                        None => self
+                            .touches
+                            .iter()
+                            .any(|touch| !self.consumed.touch_presses.contains(&touch.id())),
+                    };
+
+                pressed.into()
+            }
+            Input::TouchMotion { finger } => {
+                if self.touch_blocked() {
+                    return Vec2::ZERO.into();
+                }
+
+                let Some(finger) = finger.or_else(|| self.primary_finger()) else {
+                    return Vec2::ZERO.into();
+                };
+
+                if self.consumed.touch_motions.contains(&finger) {
+                    return Vec2::ZERO.into();
+                }
+
+                self.touch_deltas
+                    .deltas
+                    .get(&finger)
+                    .copied()
+                    .unwrap_or_default()
+                    .into()
+            }
         }
     }
 
-    fn mod_keys_pressed(&self, mod_keys: ModKeys) -> bool {
-        if !mod_keys.is_empty() && self.consumed.ui_wants_keyboard {
+    fn keyboard_blocked(&self) -> bool {
+        self.consumed.ui_wants_keyboard || *self.ignore_keyboard_mouse
+    }
+
+    fn mouse_blocked(&self) -> bool {
+        self.consumed.ui_wants_mouse || *self.ignore_keyboard_mouse
+    }
+
+    fn touch_blocked(&self) -> bool {
+        self.consumed.ui_wants_mouse || *self.ignore_touch
+    }
+
+    /// Returns the pressed finger with the lowest ID.
+    fn primary_finger(&self) -> Option<u64> {
+        self.touches.iter().map(|touch| touch.id()).min()
+    }
+
+    fn mod_keys_pressed(&self, mod_keys: ModKeys, exact: bool) -> bool {
+        self.mod_keys_pressed_ignoring(mod_keys, ModKeys::empty(), exact)
+    }
+
+    /// Like [`Self::mod_keys_pressed`], but doesn't count `ignored` as additional modifiers.
+    ///
+    /// Used for keyboard inputs, since the bound key itself can be a modifier.
+    fn mod_keys_pressed_ignoring(&self, mod_keys: ModKeys, ignored: ModKeys, exact: bool) -> bool {
+        if !mod_keys.is_empty() && self.keyboard_blocked() {
+            return false;
+        }
+
+        if exact
+            && !self.keyboard_blocked()
+            && (mod_keys | ignored)
+                .complement()
+                .iter_keys()
+                .any(|keys| self.keys.any_pressed(keys))
+        {
             return false;
         }
 
@@ -194,14 +437,34 @@ impl InputReader<'_, '_> {
     /// Resets with [`Self::update_state`].
     pub(crate) fn consume(&mut self, input: impl Into<Input>) {
         match input.into() {
-            Input::Keyboard { key, mod_keys } => {
+            Input::Keyboard { key, mod_keys } | Input::KeyRepeat { key, mod_keys } => {
                 self.consumed.keys.insert(key);
+                // Character produced by the key comes from the same press.
+                if let Some(&character) = self.pressed_characters.get(&key) {
+                    self.consumed.characters.insert(character);
+                }
+                self.consumed.mod_keys.insert(mod_keys);
+            }
+            Input::Character {
+                character,
+                mod_keys,
+            } => {
+                self.consumed.characters.insert(character);
+                // Keys that produced the character come from the same press.
+                let keys = self
+                    .pressed_characters
+                    .iter()
+                    .filter_map(|(&key, &c)| (c == character).then_some(key));
+                self.consumed.keys.extend(keys);
                 self.consumed.mod_keys.insert(mod_keys);
             }
             Input::MouseButton { button, mod_keys } => {
                 self.consumed.mouse_buttons.insert(button);
                 self.consumed.mod_keys.insert(mod_keys);
             }
+            Input::ModKeys(mod_keys) => {
+                self.consumed.mod_keys.insert(mod_keys);
+            }
             Input::MouseMotion { mod_keys } => {
                 self.consumed.mouse_motion = true;
                 self.consumed.mod_keys.insert(mod_keys);
@@ -210,9 +473,12 @@ impl InputReader<'_, '_> {
                 self.consumed.mouse_wheel = true;
                 self.consumed.mod_keys.insert(mod_keys);
             }
-            Input::GamepadButton(button) => {
+            Input::MousePosition { .. } => {
+                self.consumed.mouse_position = true;
+            }
+            Input::GamepadButton(button) | Input::GamepadTrigger(button) => {
                 let input = GamepadInput {
-                    gamepad: *self.gamepad_device,
+                    gamepad: self.gamepad_device.clone(),
                     input: button,
                 };
 
@@ -220,12 +486,26 @@ impl InputReader<'_, '_> {
             }
             Input::GamepadAxis(axis) => {
                 let input = GamepadInput {
-                    gamepad: *self.gamepad_device,
+                    gamepad: self.gamepad_device.clone(),
                     input: axis,
                 };
 
                 self.consumed.gamepad_axes.insert(input);
             }
+            Input::TouchPress {
+                finger: Some(finger),
+            } => {
+                self.consumed.touch_presses.insert(finger);
+            }
+            Input::TouchPress { finger: None } => {
+                let fingers = self.touches.iter().map(|touch| touch.id());
+                self.consumed.touch_presses.extend(fingers);
+            }
+            Input::TouchMotion { finger } => {
+                if let Some(finger) = finger.or_else(|| self.primary_finger()) {
+                    self.consumed.touch_motions.insert(finger);
+                }
+            }
         }
     }
 }
@@ -236,12 +516,16 @@ struct ConsumedInput {
     ui_wants_keyboard: bool,
     ui_wants_mouse: bool,
     keys: HashSet<KeyCode>,
+    characters: HashSet<char>,
     mod_keys: ModKeys,
     mouse_buttons: HashSet<MouseButton>,
     mouse_motion: bool,
     mouse_wheel: bool,
+    mouse_position: bool,
     gamepad_buttons: HashSet<GamepadInput<GamepadButton>>,
     gamepad_axes: HashSet<GamepadInput<GamepadAxis>>,
+    touch_presses: HashSet<u64>,
+    touch_motions: HashSet<u64>,
 }
 
 impl ConsumedInput {
@@ -249,12 +533,105 @@ impl ConsumedInput {
         self.ui_wants_keyboard = false;
         self.ui_wants_mouse = false;
         self.keys.clear();
+        self.characters.clear();
         self.mod_keys = ModKeys::empty();
         self.mouse_buttons.clear();
         self.mouse_motion = false;
         self.mouse_wheel = false;
+        self.mouse_position = false;
         self.gamepad_buttons.clear();
         self.gamepad_axes.clear();
+        self.touch_presses.clear();
+        self.touch_motions.clear();
+    }
+}
+
+/// Input from all gamepads, resolved once per frame.
+///
+/// Used for [`GamepadDevice::Any`] and [`GamepadDevice::AnyBest`] to avoid iterating over
+/// all gamepads for each binding.
+#[derive(Default)]
+struct AnyGamepadCache {
+    buttons: HashSet<GamepadButton>,
+    /// Non-zero values for each axis combined according to [`AnyGamepadReduce`].
+    axes: HashMap<GamepadAxis, f32>,
+    /// Values with the largest magnitude for each axis.
+    best_axes: HashMap<GamepadAxis, f32>,
+    /// Non-zero analog values for each button combined according to [`AnyGamepadReduce`].
+    triggers: HashMap<GamepadButton, f32>,
+    /// Analog values with the largest magnitude for each button.
+    best_triggers: HashMap<GamepadButton, f32>,
+}
+
+impl AnyGamepadCache {
+    fn update(&mut self, gamepads: &Query<&Gamepad>, reduce: AnyGamepadReduce) {
+        self.buttons.clear();
+        self.axes.clear();
+        self.best_axes.clear();
+        self.triggers.clear();
+        self.best_triggers.clear();
+        for gamepad in gamepads {
+            self.buttons.extend(gamepad.get_pressed());
+            for (&input, value) in gamepad.analog().all_axes_and_values() {
+                if value == 0.0 {
+                    continue;
+                }
+
+                match input {
+                    bevy::input::gamepad::GamepadInput::Axis(axis) => {
+                        combine(&mut self.axes, &mut self.best_axes, axis, value, reduce)
+                    }
+                    bevy::input::gamepad::GamepadInput::Button(button) => combine(
+                        &mut self.triggers,
+                        &mut self.best_triggers,
+                        button,
+                        value,
+                        reduce,
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Combines the value with the accumulated and the best values for the input.
+fn combine<T: Hash + Eq + Copy>(
+    accumulated: &mut HashMap<T, f32>,
+    best: &mut HashMap<T, f32>,
+    input: T,
+    value: f32,
+    reduce: AnyGamepadReduce,
+) {
+    accumulated
+        .entry(input)
+        .and_modify(|accumulated| *accumulated = reduce.reduce(*accumulated, value))
+        .or_insert(value);
+    best.entry(input)
+        .and_modify(|best| *best = AnyGamepadReduce::Max.reduce(*best, value))
+        .or_insert(value);
+}
+
+/// Movement of each pressed finger since the last frame.
+///
+/// [`Touch::delta`] is updated only on touch events, so it can't be used directly.
+#[derive(Default)]
+struct TouchDeltaCache {
+    positions: HashMap<u64, Vec2>,
+    deltas: HashMap<u64, Vec2>,
+}
+
+impl TouchDeltaCache {
+    fn update(&mut self, touches: &Touches) {
+        self.deltas.clear();
+        for touch in touches.iter() {
+            if let Some(position) = self.positions.get(&touch.id()) {
+                self.deltas.insert(touch.id(), touch.position() - *position);
+            }
+        }
+
+        self.positions.clear();
+        self.positions
+            .extend(touches.iter().map(|touch| (touch.id(), touch.position())));
     }
 }
 
@@ -274,8 +651,11 @@ pub(crate) struct ResetInput(Vec<Input>);
 #[cfg(test)]
 mod tests {
     use bevy::{
-        ecs::system::SystemState,
-        input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        ecs::system::{RunSystemOnce, SystemState},
+        input::{
+            mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+            touch::{touch_screen_input_system, TouchInput, TouchPhase},
+        },
     };
 
     use super::*;
@@ -300,6 +680,29 @@ mod tests {
         assert_eq!(reader.value(key), ActionValue::Bool(false));
     }
 
+    #[test]
+    fn mod_keys() {
+        let (mut world, mut state) = init_world();
+
+        let input = Input::from(ModKeys::SHIFT);
+        for key in [KeyCode::ShiftLeft, KeyCode::ShiftRight] {
+            world.resource_mut::<ButtonInput<KeyCode>>().press(key);
+
+            let mut reader = state.get_mut(&mut world);
+            reader.update_state();
+            assert_eq!(reader.value(input), ActionValue::Bool(true), "{key:?}");
+            assert_eq!(
+                reader.value(ModKeys::SHIFT | ModKeys::CONTROL),
+                ActionValue::Bool(false)
+            );
+
+            reader.consume(input);
+            assert_eq!(reader.value(input), ActionValue::Bool(false));
+
+            world.resource_mut::<ButtonInput<KeyCode>>().release(key);
+        }
+    }
+
     #[test]
     fn mouse_button() {
         let (mut world, mut state) = init_world();
@@ -364,6 +767,37 @@ mod tests {
         assert_eq!(reader.value(input), ActionValue::Axis2D(Vec2::ZERO));
     }
 
+    #[test]
+    fn mouse_wheel_normalization() {
+        let (mut world, mut state) = init_world();
+
+        let input = Input::mouse_wheel();
+        let pixels_per_line = MouseScrollNormalization::DEFAULT_PIXELS_PER_LINE;
+        for (normalization, expected) in [
+            (MouseScrollNormalization::lines(), Vec2::Y),
+            (
+                MouseScrollNormalization::pixels(),
+                Vec2::Y * pixels_per_line,
+            ),
+        ] {
+            world.insert_resource(normalization);
+            for (unit, delta) in [
+                (MouseScrollUnit::Line, Vec2::Y),
+                (MouseScrollUnit::Pixel, Vec2::Y * pixels_per_line),
+            ] {
+                world.insert_resource(AccumulatedMouseScroll { unit, delta });
+
+                let mut reader = state.get_mut(&mut world);
+                reader.update_state();
+                assert_eq!(
+                    reader.value(input),
+                    ActionValue::Axis2D(expected),
+                    "`{unit:?}` should be normalized with `{normalization:?}`"
+                );
+            }
+        }
+    }
+
     #[test]
     fn gamepad_button() {
         let (mut world, mut state) = init_world();
@@ -407,6 +841,7 @@ mod tests {
         world.spawn(gamepad2);
 
         let mut reader = state.get_mut(&mut world);
+        reader.update_state();
         assert_eq!(reader.value(button1), ActionValue::Bool(true));
         assert_eq!(reader.value(button2), ActionValue::Bool(true));
         assert_eq!(reader.value(GamepadButton::North), ActionValue::Bool(false));
@@ -468,6 +903,7 @@ mod tests {
         world.spawn(gamepad2);
 
         let mut reader = state.get_mut(&mut world);
+        reader.update_state();
         assert_eq!(reader.value(axis1), ActionValue::Axis1D(1.0));
         assert_eq!(reader.value(axis2), ActionValue::Axis1D(1.0));
         assert_eq!(
@@ -482,6 +918,225 @@ mod tests {
         assert_eq!(reader.value(axis2), ActionValue::Axis1D(0.0));
     }
 
+    #[test]
+    fn gamepad_trigger() {
+        let (mut world, mut state) = init_world();
+
+        let button = GamepadButton::RightTrigger2;
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(button, 0.3);
+        let gamepad_entity = world.spawn(gamepad).id();
+
+        let input = Input::gamepad_trigger(button);
+        let mut reader = state.get_mut(&mut world);
+        reader.set_gamepad(gamepad_entity);
+        assert_eq!(reader.value(input), ActionValue::Axis1D(0.3));
+        assert_eq!(
+            reader.value(button),
+            ActionValue::Bool(false),
+            "button should report only the pressed state"
+        );
+        assert_eq!(
+            reader.value(Input::gamepad_trigger(GamepadButton::LeftTrigger2)),
+            ActionValue::Axis1D(0.0)
+        );
+
+        reader.consume(button);
+        assert_eq!(reader.value(input), ActionValue::Axis1D(0.0));
+    }
+
+    #[test]
+    fn any_gamepad_trigger() {
+        let (mut world, mut state) = init_world();
+        world.insert_resource(AnyGamepadReduce::Sum);
+
+        let button = GamepadButton::RightTrigger2;
+        for value in [0.25, 0.5] {
+            let mut gamepad = Gamepad::default();
+            gamepad.analog_mut().set(button, value);
+            world.spawn(gamepad);
+        }
+
+        let input = Input::gamepad_trigger(button);
+        let mut reader = state.get_mut(&mut world);
+        reader.update_state();
+        assert_eq!(reader.value(input), ActionValue::Axis1D(0.75));
+
+        reader.set_gamepad(GamepadDevice::AnyBest);
+        assert_eq!(reader.value(input), ActionValue::Axis1D(0.5));
+
+        reader.consume(input);
+        assert_eq!(reader.value(input), ActionValue::Axis1D(0.0));
+    }
+
+    #[test]
+    fn any_gamepad_reduce() {
+        let (mut world, mut state) = init_world();
+
+        let axis = GamepadAxis::LeftStickX;
+        for value in [0.25, -0.5] {
+            let mut gamepad = Gamepad::default();
+            gamepad.analog_mut().set(axis, value);
+            world.spawn(gamepad);
+        }
+
+        let first = world
+            .query::<&Gamepad>()
+            .iter(&world)
+            .find_map(|gamepad| gamepad.get(axis))
+            .unwrap();
+
+        for (reduce, expected) in [
+            (AnyGamepadReduce::First, first),
+            (AnyGamepadReduce::Sum, -0.25),
+            (AnyGamepadReduce::Max, -0.5),
+        ] {
+            world.insert_resource(reduce);
+            let mut reader = state.get_mut(&mut world);
+            reader.update_state();
+            assert_eq!(reader.value(axis), expected.into(), "{reduce:?}");
+        }
+    }
+
+    #[test]
+    fn mouse_position() {
+        let (mut world, mut state) = init_world();
+
+        let value = Vec2::new(10.0, 20.0);
+        let mut primary_window = Window::default();
+        primary_window.set_cursor_position(Some(value));
+        world.spawn((primary_window, PrimaryWindow));
+        let other_window = world.spawn(Window::default()).id();
+
+        let input = Input::mouse_position();
+        let mut reader = state.get_mut(&mut world);
+        reader.update_state();
+        assert_eq!(reader.value(input), ActionValue::Axis2D(value));
+        assert_eq!(
+            reader.value(Input::MousePosition {
+                window: Some(other_window)
+            }),
+            ActionValue::Axis2D(Vec2::ZERO),
+            "cursor outside the window should be captured as zero"
+        );
+
+        reader.consume(input);
+        assert_eq!(reader.value(input), ActionValue::Axis2D(Vec2::ZERO));
+    }
+
+    #[test]
+    fn touch() {
+        let (mut world, mut state) = init_world();
+
+        for id in [1, 2] {
+            world.send_event(TouchInput {
+                phase: TouchPhase::Started,
+                position: Vec2::ZERO,
+                window: Entity::PLACEHOLDER,
+                force: None,
+                id,
+            });
+        }
+        world.run_system_once(touch_screen_input_system).unwrap();
+
+        let input = Input::TouchPress { finger: Some(1) };
+        let mut reader = state.get_mut(&mut world);
+        reader.update_state();
+        assert_eq!(reader.value(input), ActionValue::Bool(true));
+        assert_eq!(
+            reader.value(Input::TouchPress { finger: Some(3) }),
+            ActionValue::Bool(false)
+        );
+
+        reader.consume(input);
+        assert_eq!(reader.value(input), ActionValue::Bool(false));
+        assert_eq!(
+            reader.value(Input::touch_press()),
+            ActionValue::Bool(true),
+            "second finger should still be available"
+        );
+
+        reader.consume(Input::touch_press());
+        assert_eq!(reader.value(Input::touch_press()), ActionValue::Bool(false));
+    }
+
+    #[test]
+    fn gamepad_set() {
+        let (mut world, mut state) = init_world();
+
+        let axis = GamepadAxis::LeftStickX;
+        let button = GamepadButton::South;
+        let mut entities = Vec::new();
+        for value in [0.25, 0.5, 1.0] {
+            let mut gamepad = Gamepad::default();
+            gamepad.analog_mut().set(axis, value);
+            entities.push(world.spawn(gamepad).id());
+        }
+
+        let mut gamepad = world.get_mut::<Gamepad>(entities[2]).unwrap();
+        gamepad.digital_mut().press(button);
+
+        let gamepad_device = GamepadDevice::from([entities[0], entities[1]]);
+        let mut reader = state.get_mut(&mut world);
+        reader.update_state();
+        reader.set_gamepad(gamepad_device.clone());
+        assert_eq!(
+            reader.value(axis),
+            0.75.into(),
+            "only gamepads from the set should be summed"
+        );
+        assert_eq!(
+            reader.value(button),
+            false.into(),
+            "buttons from gamepads outside the set should be ignored"
+        );
+
+        reader.consume(axis);
+        assert_eq!(reader.value(axis), 0.0.into());
+
+        reader.set_gamepad(entities[2]);
+        assert_eq!(reader.value(axis), 1.0.into());
+
+        let mut gamepad = world.get_mut::<Gamepad>(entities[1]).unwrap();
+        gamepad.digital_mut().press(button);
+
+        let mut reader = state.get_mut(&mut world);
+        reader.set_gamepad(gamepad_device);
+        assert_eq!(reader.value(button), true.into());
+    }
+
+    #[test]
+    fn any_best_gamepad() {
+        let (mut world, mut state) = init_world();
+        world.insert_resource(AnyGamepadReduce::Sum);
+
+        let axis = GamepadAxis::LeftStickX;
+        for value in [0.003, 0.8] {
+            let mut gamepad = Gamepad::default();
+            gamepad.analog_mut().set(axis, value);
+            gamepad.digital_mut().press(GamepadButton::South);
+            world.spawn(gamepad);
+        }
+
+        let mut reader = state.get_mut(&mut world);
+        reader.update_state();
+        reader.set_gamepad(GamepadDevice::AnyBest);
+        assert_eq!(reader.value(axis), 0.8.into());
+        assert_eq!(reader.value(GamepadButton::South), true.into());
+        assert_eq!(reader.value(GamepadButton::North), false.into());
+
+        reader.set_gamepad(GamepadDevice::Any);
+        assert_eq!(
+            reader.value(axis),
+            (0.003 + 0.8).into(),
+            "should still respect reduction for `Any`"
+        );
+
+        reader.set_gamepad(GamepadDevice::AnyBest);
+        reader.consume(axis);
+        assert_eq!(reader.value(axis), 0.0.into());
+    }
+
     #[test]
     fn keyboard_with_modifier() {
         let (mut world, mut state) = init_world();
@@ -519,6 +1174,33 @@ mod tests {
         assert_eq!(reader.value(other_key), ActionValue::Bool(true));
     }
 
+    #[test]
+    fn exact_mod_keys() {
+        let (mut world, mut state) = init_world();
+
+        let key = KeyCode::KeyS;
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::ControlRight);
+        keys.press(key);
+
+        let input = key.with_mod_keys(ModKeys::CONTROL);
+        let reader = state.get_mut(&mut world);
+        assert_eq!(reader.exact_value(key), ActionValue::Bool(false));
+        assert_eq!(reader.exact_value(input), ActionValue::Bool(true));
+        assert_eq!(
+            reader.exact_value(ModKeys::CONTROL),
+            ActionValue::Bool(true)
+        );
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftLeft);
+
+        let reader = state.get_mut(&mut world);
+        assert_eq!(reader.exact_value(input), ActionValue::Bool(false));
+        assert_eq!(reader.value(input), ActionValue::Bool(true));
+    }
+
     #[test]
     fn mouse_button_with_modifier() {
         let (mut world, mut state) = init_world();
@@ -610,6 +1292,53 @@ mod tests {
         assert_eq!(reader.value(input), ActionValue::Axis2D(Vec2::ZERO));
     }
 
+    #[test]
+    fn any_gamepad_cache() {
+        let (mut world, mut state) = init_world();
+
+        let mut gamepad1 = Gamepad::default();
+        gamepad1.digital_mut().press(GamepadButton::South);
+        gamepad1.analog_mut().set(GamepadAxis::LeftStickX, 0.5);
+        world.spawn(gamepad1);
+
+        let mut gamepad2 = Gamepad::default();
+        gamepad2.digital_mut().press(GamepadButton::East);
+        gamepad2.analog_mut().set(GamepadAxis::LeftStickX, 0.0);
+        gamepad2.analog_mut().set(GamepadAxis::RightStickY, -2.0);
+        world.spawn(gamepad2);
+
+        let buttons = [
+            GamepadButton::South,
+            GamepadButton::East,
+            GamepadButton::North,
+            GamepadButton::West,
+        ];
+        let axes = [
+            GamepadAxis::LeftStickX,
+            GamepadAxis::LeftStickY,
+            GamepadAxis::RightStickX,
+            GamepadAxis::RightStickY,
+        ];
+
+        let mut reader = state.get_mut(&mut world);
+        reader.update_state();
+        for button in buttons {
+            let expected = reader
+                .gamepads
+                .iter()
+                .any(|gamepad| gamepad.pressed(button));
+            assert_eq!(reader.value(button), expected.into(), "{button:?}");
+        }
+        for axis in axes {
+            let expected = reader
+                .gamepads
+                .iter()
+                .find_map(|gamepad| gamepad.get_unclamped(axis).filter(|&value| value != 0.0))
+                .unwrap_or_default();
+            assert_eq!(reader.value(axis), expected.into(), "{axis:?}");
+        }
+    }
+
     #[test]
     fn ui_input() {
         let (mut world, mut state) = init_world();
@@ -649,13 +1378,18 @@ mod tests {
         let mut world = World::new();
         world.init_resource::<ButtonInput<KeyCode>>();
         world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<Events<KeyboardInput>>();
         world.init_resource::<Events<MouseMotion>>();
         world.init_resource::<Events<MouseWheel>>();
         world.init_resource::<ButtonInput<GamepadButton>>();
         world.init_resource::<Axis<GamepadAxis>>();
         world.init_resource::<AccumulatedMouseMotion>();
         world.init_resource::<AccumulatedMouseScroll>();
+        world.init_resource::<Touches>();
+        world.init_resource::<Events<TouchInput>>();
         world.init_resource::<ResetInput>();
+        world.init_resource::<AnyGamepadReduce>();
+        world.init_resource::<MouseScrollNormalization>();
 
         let state = SystemState::<InputReader>::new(&mut world);
 
@@ -1,21 +1,38 @@
+pub mod action_mock;
+pub mod action_read;
+pub mod common_conditions;
 pub mod context_instance;
 pub mod events;
 pub mod input_action;
 pub mod input_bind;
 pub mod input_condition;
 pub mod input_modifier;
+pub mod input_setting;
+#[cfg(feature = "presets")]
 pub mod preset;
 
 use std::{
     any::{self, TypeId},
     cmp::Reverse,
+    marker::PhantomData,
     mem,
+    ops::Deref,
 };
 
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
 
-use crate::input::input_reader::{InputReader, ResetInput};
-use context_instance::ContextInstance;
+use crate::{
+    input::{
+        input_reader::{InputReader, ResetInput},
+        Input,
+    },
+    EnhancedInputSystem,
+};
+use action_mock::MockDirectives;
+use context_instance::{ActionBind, ContextInstance, EntityActions, SharedData, UpdateStats};
+use input_action::InputAction;
+use input_bind::InputBind;
+use input_setting::InputSettings;
 
 /// An extension trait for [`App`] to register contexts.
 ///
@@ -35,6 +52,12 @@ use context_instance::ContextInstance;
 pub trait ContextAppExt {
     /// Registers an input context.
     fn add_input_context<C: InputContext>(&mut self) -> &mut Self;
+
+    /// Registers a boolean resource to be readable by modifiers and conditions.
+    ///
+    /// The value is snapshotted into [`InputSettings`] before evaluating contexts.
+    /// See [`SettingNegate`](input_modifier::setting_negate::SettingNegate) for an example.
+    fn add_input_setting<R: Resource + Deref<Target = bool>>(&mut self) -> &mut Self;
 }
 
 impl ContextAppExt for App {
@@ -47,10 +70,20 @@ impl ContextAppExt for App {
 
         self
     }
+
+    fn add_input_setting<R: Resource + Deref<Target = bool>>(&mut self) -> &mut Self {
+        debug!("registering setting `{}`", any::type_name::<R>());
+
+        self.init_resource::<InputSettings>().add_systems(
+            PreUpdate,
+            input_setting::snapshot_setting::<R>.before(EnhancedInputSystem),
+        )
+    }
 }
 
 fn add_instance<C: InputContext>(
     trigger: Trigger<OnAdd, C>,
+    mut commands: Commands,
     mut set: ParamSet<(&World, ResMut<ContextInstances>)>,
 ) {
     // We need to borrow both the world and contexts,
@@ -59,18 +92,23 @@ fn add_instance<C: InputContext>(
     // So we just move it from the resource and put it back.
     let mut instances = mem::take(&mut *set.p1());
     instances.add::<C>(set.p0(), trigger.entity());
+    let priority = instances.priority::<C>();
     *set.p1() = instances;
+
+    commands.trigger_targets(ContextActivated::<C>::new(priority), trigger.entity());
 }
 
 fn rebuild_instance<C: InputContext>(
-    _trigger: Trigger<RebuildInputContexts>,
+    trigger: Trigger<RebuildInputContexts>,
     mut set: ParamSet<(&World, ResMut<ContextInstances>, ResMut<ResetInput>)>,
     mut commands: Commands,
     time: Res<Time<Virtual>>,
 ) {
     let mut instances = mem::take(&mut *set.p1());
     let mut reset_input = mem::take(&mut *set.p2());
-    instances.rebuild::<C>(set.p0(), &mut commands, &mut reset_input, &time);
+    let target = trigger.entity();
+    let target = (target != Entity::PLACEHOLDER).then_some(target);
+    instances.rebuild::<C>(set.p0(), &mut commands, &mut reset_input, &time, target);
     *set.p1() = instances;
     *set.p2() = reset_input;
 }
@@ -83,32 +121,48 @@ fn remove_instance<C: InputContext>(
     time: Res<Time<Virtual>>,
 ) {
     instances.remove::<C>(&mut commands, &mut reset_input, &time, trigger.entity());
+    commands.trigger_targets(
+        ContextDeactivated::<C>::new(instances.priority::<C>()),
+        trigger.entity(),
+    );
 }
 
 /// Stores instantiated [`InputContext`]s.
 #[derive(Resource, Default)]
-pub struct ContextInstances(Vec<InstanceGroup>);
+pub struct ContextInstances {
+    groups: Vec<InstanceGroup>,
+    /// Priorities overridden by [`Self::set_priority`].
+    priorities: HashMap<TypeId, isize>,
+    /// Counters from the last [`Self::update`].
+    stats: UpdateStats,
+    /// Actions of all instances, reused between updates to avoid allocations.
+    entity_actions: EntityActions,
+}
 
 impl ContextInstances {
     fn add<C: InputContext>(&mut self, world: &World, entity: Entity) {
         debug!("adding `{}` to `{entity}`", any::type_name::<C>());
 
         if let Some(group) = self
-            .0
+            .groups
             .iter_mut()
             .find(|group| group.type_id == TypeId::of::<C>())
         {
-            let ctx = C::context_instance(world, entity);
+            let ctx = instantiate::<C>(world, entity);
             group.instances.push((entity, ctx));
         } else {
-            let priority = Reverse(C::PRIORITY);
+            let priority = self
+                .priorities
+                .get(&TypeId::of::<C>())
+                .copied()
+                .unwrap_or(C::PRIORITY);
             let index = self
-                .0
-                .binary_search_by_key(&priority, |group| Reverse(group.priority))
+                .groups
+                .binary_search_by_key(&Reverse(priority), |group| Reverse(group.priority))
                 .unwrap_or_else(|e| e);
 
-            let group = InstanceGroup::new::<C>(world, entity);
-            self.0.insert(index, group);
+            let group = InstanceGroup::new::<C>(world, entity, priority);
+            self.groups.insert(index, group);
         }
     }
 
@@ -118,16 +172,21 @@ impl ContextInstances {
         commands: &mut Commands,
         reset_input: &mut ResetInput,
         time: &Time<Virtual>,
+        target: Option<Entity>,
     ) {
         if let Some(group) = self
-            .0
+            .groups
             .iter_mut()
             .find(|group| group.type_id == TypeId::of::<C>())
         {
             debug!("rebuilding `{}`", any::type_name::<C>());
-            for (entity, ctx) in &mut group.instances {
+            for (entity, ctx) in group
+                .instances
+                .iter_mut()
+                .filter(|(entity, _)| target.is_none_or(|target| *entity == target))
+            {
                 ctx.trigger_removed(commands, reset_input, time, *entity);
-                *ctx = C::context_instance(world, *entity);
+                *ctx = instantiate::<C>(world, *entity);
             }
         }
     }
@@ -142,12 +201,12 @@ impl ContextInstances {
         debug!("removing `{}` from `{entity}`", any::type_name::<C>());
 
         let group_index = self
-            .0
+            .groups
             .iter()
             .position(|group| group.type_id == TypeId::of::<C>())
             .expect("context should be instantiated before removal");
 
-        let group = &mut self.0[group_index];
+        let group = &mut self.groups[group_index];
         let entity_index = group
             .instances
             .iter()
@@ -160,7 +219,7 @@ impl ContextInstances {
         if group.instances.is_empty() {
             // Remove the group if no entity references it.
             debug!("removing empty `{}`", any::type_name::<C>());
-            self.0.remove(group_index);
+            self.groups.remove(group_index);
         }
     }
 
@@ -169,14 +228,111 @@ impl ContextInstances {
         commands: &mut Commands,
         reader: &mut InputReader,
         time: &Time<Virtual>,
+        settings: &InputSettings,
     ) {
-        for group in &mut self.0 {
+        self.entity_actions.clear();
+        let instances = self.groups.iter().flat_map(|group| &group.instances);
+        if instances.clone().any(|(_, ctx)| ctx.reads_entity_actions()) {
+            // Collect before the update to make the result independent of the evaluation order.
+            self.entity_actions
+                .extend(instances.flat_map(|(entity, ctx)| {
+                    ctx.actions()
+                        .map(|(type_id, action)| ((*entity, type_id), action))
+                }));
+        }
+
+        let shared = SharedData {
+            entity_actions: &self.entity_actions,
+            settings,
+            window_focused: reader.window_focused(),
+        };
+
+        self.stats = Default::default();
+        for group in &mut self.groups {
             for (entity, ctx) in &mut group.instances {
-                ctx.update(commands, reader, time, *entity);
+                self.stats += ctx.update(commands, reader, time, &shared, *entity);
+            }
+        }
+    }
+
+    /// Applies directives to the matching actions for the next update.
+    pub(crate) fn apply_directives(&mut self, directives: &MockDirectives) {
+        for (directive_entity, type_id, state, value) in directives.iter() {
+            let applied = self
+                .groups
+                .iter_mut()
+                .flat_map(|group| &mut group.instances)
+                .filter(|(entity, _)| *entity == directive_entity)
+                .fold(false, |applied, (_, ctx)| {
+                    ctx.apply_directive(type_id, state, value) || applied
+                });
+
+            if !applied {
+                trace!("ignoring directive for `{directive_entity}` without a matching action");
             }
         }
     }
 
+    /// Returns counters from the last update.
+    #[cfg(feature = "diagnostics")]
+    pub(crate) fn stats(&self) -> UpdateStats {
+        self.stats
+    }
+
+    /// Overrides [`InputContext::PRIORITY`] for context `C` at runtime.
+    ///
+    /// Affects all instances of `C` starting from the next update and persists
+    /// even if all instances are removed. Re-sorts contexts, which is `O(n log n)`
+    /// where `n` is the number of instantiated context types, so avoid calling it every frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// fn open_inventory(mut instances: ResMut<ContextInstances>) {
+    ///     // Evaluate inventory before the player to let it consume shared inputs.
+    ///     instances.set_priority::<Inventory>(Player::PRIORITY + 1);
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # impl InputContext for Player {
+    /// # fn context_instance(_world: &World, _entity: Entity) -> ContextInstance { Default::default() }
+    /// # }
+    /// # #[derive(Component)]
+    /// # struct Inventory;
+    /// # impl InputContext for Inventory {
+    /// # fn context_instance(_world: &World, _entity: Entity) -> ContextInstance { Default::default() }
+    /// # }
+    /// ```
+    pub fn set_priority<C: InputContext>(&mut self, priority: isize) {
+        debug!(
+            "setting priority of `{}` to {priority}",
+            any::type_name::<C>()
+        );
+
+        let type_id = TypeId::of::<C>();
+        self.priorities.insert(type_id, priority);
+        if let Some(group) = self
+            .groups
+            .iter_mut()
+            .find(|group| group.type_id == type_id)
+        {
+            group.priority = priority;
+            self.groups.sort_by_key(|group| Reverse(group.priority));
+        }
+    }
+
+    /// Returns the current priority of context `C`.
+    ///
+    /// Equals to [`InputContext::PRIORITY`] unless overridden by [`Self::set_priority`].
+    pub fn priority<C: InputContext>(&self) -> isize {
+        self.priorities
+            .get(&TypeId::of::<C>())
+            .copied()
+            .unwrap_or(C::PRIORITY)
+    }
+
     #[deprecated = "use `ContextInstances::get_context` instead"]
     pub fn get<C: InputContext>(&self, instance_entity: Entity) -> Option<&ContextInstance> {
         self.get_context::<C>(instance_entity)
@@ -190,7 +346,7 @@ impl ContextInstances {
         instance_entity: Entity,
     ) -> Option<&ContextInstance> {
         let group = self
-            .0
+            .groups
             .iter()
             .find(|group| group.type_id == TypeId::of::<C>())?;
 
@@ -203,6 +359,123 @@ impl ContextInstances {
         })
     }
 
+    /// Returns a mutable context instance for an entity, if it exists.
+    ///
+    /// Useful for runtime changes, such as [`ActionBind::mock`].
+    /// For panicking version see [`Self::context_mut`].
+    pub fn get_context_mut<C: InputContext>(
+        &mut self,
+        instance_entity: Entity,
+    ) -> Option<&mut ContextInstance> {
+        let group = self
+            .groups
+            .iter_mut()
+            .find(|group| group.type_id == TypeId::of::<C>())?;
+
+        group.instances.iter_mut().find_map(|(entity, ctx)| {
+            if *entity == instance_entity {
+                Some(ctx)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns bindings of actions from all contexts of the entity that already use the input.
+    ///
+    /// See also [`ContextInstance::binding_conflicts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// fn rebind(trigger: Trigger<Rebind>, instances: Res<ContextInstances>) {
+    ///     for action_bind in instances.binding_conflicts(trigger.entity(), trigger.input) {
+    ///         warn!("`{:?}` is already used by `{}`", trigger.input, action_bind.action_name());
+    ///     }
+    /// }
+    /// # #[derive(Event)]
+    /// # struct Rebind {
+    /// #     input: Input,
+    /// # }
+    /// ```
+    pub fn binding_conflicts(
+        &self,
+        instance_entity: Entity,
+        input: impl Into<Input>,
+    ) -> impl Iterator<Item = &ActionBind> {
+        let input = input.into();
+        self.groups
+            .iter()
+            .flat_map(|group| &group.instances)
+            .filter(move |(entity, _)| *entity == instance_entity)
+            .flat_map(move |(_, ctx)| ctx.binding_conflicts(input))
+    }
+
+    /// Returns bindings of action `A` from all contexts of the entity.
+    ///
+    /// Contexts are visited in their evaluation order.
+    /// Useful to display currently assigned inputs in UI.
+    /// See also [`ActionBind::bindings`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// fn jump_hint(player: Single<Entity, With<Player>>, instances: Res<ContextInstances>) {
+    ///     for binding in instances.action_bindings::<Jump>(*player) {
+    ///         info!("press `{}` to jump", binding.input);
+    ///     }
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Jump;
+    /// ```
+    pub fn action_bindings<A: InputAction>(
+        &self,
+        instance_entity: Entity,
+    ) -> impl Iterator<Item = &InputBind> {
+        self.groups
+            .iter()
+            .flat_map(|group| &group.instances)
+            .filter(move |(entity, _)| *entity == instance_entity)
+            .filter_map(|(_, ctx)| ctx.get_action_bind::<A>())
+            .flat_map(|action_bind| action_bind.bindings())
+    }
+
+    /// Returns names of actions from all contexts of the entity that triggered
+    /// [`Fired`](events::Fired) since the last update.
+    ///
+    /// Contexts are visited in their evaluation order.
+    /// See also [`ContextInstance::fired_actions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// fn log_actions(players: Query<Entity, With<Player>>, instances: Res<ContextInstances>) {
+    ///     for entity in &players {
+    ///         for action_name in instances.fired_actions(entity) {
+    ///             info!("`{entity}` fired `{action_name}`");
+    ///         }
+    ///     }
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// ```
+    pub fn fired_actions(&self, instance_entity: Entity) -> impl Iterator<Item = &str> {
+        self.groups
+            .iter()
+            .flat_map(|group| &group.instances)
+            .filter(move |(entity, _)| *entity == instance_entity)
+            .flat_map(|(_, ctx)| ctx.fired_actions())
+    }
+
     /// Returns a context instance for an entity.
     ///
     /// For a more ergonomic API, it's recommended to react on [`events`].
@@ -248,6 +521,69 @@ impl ContextInstances {
             )
         })
     }
+
+    /// Returns a mutable context instance for an entity.
+    ///
+    /// For non-panicking version see [`Self::get_context_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is not registered as an input context or the entity doesn't have this component.
+    pub fn context_mut<C: InputContext>(
+        &mut self,
+        instance_entity: Entity,
+    ) -> &mut ContextInstance {
+        self.get_context_mut::<C>(instance_entity)
+            .unwrap_or_else(|| {
+                panic!(
+                    "entity `{instance_entity}` should have component `{}` registered as input context",
+                    any::type_name::<C>()
+                )
+            })
+    }
+}
+
+/// Number of times [`ContextInstances`] were evaluated.
+///
+/// Incremented once per run of [`EnhancedInputSystem`] before evaluating contexts,
+/// so all action values read after it correspond to the current epoch.
+/// Useful to tag captured inputs with the exact evaluation for networking or replays.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// fn capture_input(
+///     epoch: Res<InputEpoch>,
+///     instances: Res<ContextInstances>,
+///     player: Single<Entity, With<Player>>,
+/// ) {
+///     let ctx = instances.context::<Player>(*player);
+///     let value = ctx.action::<Move>().value();
+///     info!("epoch {}: `{value:?}`", **epoch);
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// # impl InputContext for Player {
+/// # fn context_instance(_world: &World, _entity: Entity) -> ContextInstance { Default::default() }
+/// # }
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = Vec2)]
+/// # struct Move;
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy, Deref)]
+pub struct InputEpoch(pub(crate) u64);
+
+/// Creates a new instance of context `C` for the entity.
+///
+/// In debug builds also warns about bindings that always output zero.
+fn instantiate<C: InputContext>(world: &World, entity: Entity) -> ContextInstance {
+    let ctx = C::context_instance(world, entity);
+    if cfg!(debug_assertions) {
+        ctx.warn_dead_bindings();
+    }
+    ctx
 }
 
 /// Instances of [`InputContext`] for the same type.
@@ -259,18 +595,18 @@ struct InstanceGroup {
 
 impl InstanceGroup {
     #[must_use]
-    fn new<C: InputContext>(world: &World, entity: Entity) -> Self {
+    fn new<C: InputContext>(world: &World, entity: Entity, priority: isize) -> Self {
         let type_id = TypeId::of::<C>();
-        let ctx = C::context_instance(world, entity);
+        let ctx = instantiate::<C>(world, entity);
         Self {
             type_id,
-            priority: C::PRIORITY,
+            priority,
             instances: vec![(entity, ctx)],
         }
     }
 }
 
-/// Contexts are components that associate entities with [`InputAction`](input_action::InputAction)s.
+/// Contexts are components that associate entities with [`InputAction`]s.
 ///
 /// Inserting this component associates [`ContextInstance`] for this
 /// entity in a resource.
@@ -322,12 +658,13 @@ pub trait InputContext: Component {
     ///
     /// Ordering is global.
     /// Contexts with a higher priority evaluated first.
+    /// Can be overridden at runtime with [`ContextInstances::set_priority`].
     const PRIORITY: isize = 0;
 
     /// Creates a new instance for the given entity.
     ///
     /// In the implementation you need call [`ContextInstance::bind`]
-    /// to associate it with [`InputAction`](input_action::InputAction)s.
+    /// to associate it with [`InputAction`]s.
     ///
     /// The function is called on each context instantiation.
     /// You can also rebuild all contexts by triggering [`RebuildInputContexts`].
@@ -338,8 +675,82 @@ pub trait InputContext: Component {
 /// A trigger that causes the reconstruction of all active context maps.
 ///
 /// Use it when you change your application settings and want to reload the mappings.
+/// Trigger it globally to rebuild contexts for all entities or target specific
+/// entities to rebuild only their contexts.
 ///
 /// This will also reset all actions to [`ActionState::None`](crate::input_context::context_instance::ActionState::None)
-/// and trigger the corresponding events.
+/// and trigger the corresponding events. Inputs that are still held will be picked
+/// up again on the next update, unless the action has [`InputAction::REQUIRE_RESET`]
+/// enabled. In this case, its inputs will be ignored until released.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// fn reload_bindings(mut commands: Commands, player: Single<Entity, With<Player>>) {
+///     // Rebuild only player contexts.
+///     commands.trigger_targets(RebuildInputContexts, *player);
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// ```
 #[derive(Event)]
 pub struct RebuildInputContexts;
+
+/// Triggers on an entity when context `C` is instantiated for it.
+///
+/// Triggered only for contexts registered with [`ContextAppExt::add_input_context`].
+#[derive(Debug, Event)]
+pub struct ContextActivated<C: InputContext> {
+    /// Priority of the context at the moment of activation.
+    ///
+    /// See also [`ContextInstances::set_priority`].
+    pub priority: isize,
+    marker: PhantomData<C>,
+}
+
+impl<C: InputContext> ContextActivated<C> {
+    fn new(priority: isize) -> Self {
+        Self {
+            priority,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: InputContext> Clone for ContextActivated<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: InputContext> Copy for ContextActivated<C> {}
+
+/// Triggers on an entity when context `C` is removed from it.
+///
+/// Triggered after all its actions are reset, so the events for them arrive before this event.
+/// See also [`ContextActivated`].
+#[derive(Debug, Event)]
+pub struct ContextDeactivated<C: InputContext> {
+    /// Priority of the context at the moment of deactivation.
+    pub priority: isize,
+    marker: PhantomData<C>,
+}
+
+impl<C: InputContext> ContextDeactivated<C> {
+    fn new(priority: isize) -> Self {
+        Self {
+            priority,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: InputContext> Clone for ContextDeactivated<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: InputContext> Copy for ContextDeactivated<C> {}
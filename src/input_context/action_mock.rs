@@ -0,0 +1,157 @@
+use std::{any::TypeId, time::Duration};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use super::{context_instance::ActionState, input_action::InputAction};
+use crate::action_value::ActionValue;
+
+/// Scripted state and value for an action that replaces input reading.
+///
+/// Mocks are queued with [`ActionBind::mock`](super::context_instance::ActionBind::mock)
+/// and played sequentially: once the span of the current mock expires, the next one
+/// is applied starting from the next update. When the queue is empty, the action
+/// reads its inputs as usual.
+///
+/// Useful for scripted tutorials, AI or tests.
+#[derive(Clone, Copy, Debug)]
+pub struct ActionMock {
+    pub state: ActionState,
+    pub value: ActionValue,
+    pub span: MockSpan,
+    elapsed: Duration,
+    updates: u32,
+}
+
+impl ActionMock {
+    #[must_use]
+    pub fn new(state: ActionState, value: impl Into<ActionValue>, span: MockSpan) -> Self {
+        Self {
+            state,
+            value: value.into(),
+            span,
+            elapsed: Duration::ZERO,
+            updates: 0,
+        }
+    }
+
+    /// Creates a mock that is applied only for the next update.
+    #[must_use]
+    pub fn once(state: ActionState, value: impl Into<ActionValue>) -> Self {
+        Self::new(state, value, MockSpan::Updates(1))
+    }
+
+    /// Creates a mock that fires the action with the given value for the next update.
+    ///
+    /// See also [`Self::once`].
+    #[must_use]
+    pub fn fired(value: impl Into<ActionValue>) -> Self {
+        Self::once(ActionState::Fired, value)
+    }
+
+    /// Returns how long the mock will be applied.
+    ///
+    /// Decreases with each update while the mock is active.
+    pub fn remaining(&self) -> MockSpan {
+        match self.span {
+            MockSpan::Updates(updates) => MockSpan::Updates(updates.saturating_sub(self.updates)),
+            MockSpan::Duration(duration) => {
+                MockSpan::Duration(duration.saturating_sub(self.elapsed))
+            }
+        }
+    }
+
+    /// Advances the mock by one update and returns `true` if it has expired.
+    pub(super) fn advance(&mut self, time: &Time<Virtual>) -> bool {
+        self.elapsed += time.delta();
+        self.updates += 1;
+        self.expired()
+    }
+
+    /// Returns `true` if the span of the mock is over.
+    ///
+    /// Could be `true` before the first update for mocks with zero span.
+    pub(super) fn expired(&self) -> bool {
+        match self.span {
+            MockSpan::Updates(updates) => self.updates >= updates,
+            MockSpan::Duration(duration) => self.elapsed >= duration,
+        }
+    }
+}
+
+/// How long an [`ActionMock`] is applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MockSpan {
+    /// Number of context updates.
+    Updates(u32),
+    /// Real duration, measured by [`Time<Virtual>`].
+    Duration(Duration),
+}
+
+impl From<Duration> for MockSpan {
+    fn from(value: Duration) -> Self {
+        Self::Duration(value)
+    }
+}
+
+/// Scripted states and values for actions of many entities at once.
+///
+/// Each update, every directive overrides the state and value of the matching action
+/// in all contexts of the entity, taking precedence over its inputs and queued [`ActionMock`]s.
+/// Queued mocks don't advance while a directive is applied.
+/// Directives persist until removed.
+///
+/// Useful for integration tests that script inputs centrally instead of
+/// mocking each action individually.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// fn script_players(mut directives: ResMut<MockDirectives>, players: Query<Entity, With<Player>>) {
+///     for entity in &players {
+///         directives.insert::<Jump>(entity, ActionState::Fired, true);
+///     }
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Jump;
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct MockDirectives(HashMap<(Entity, TypeId), (ActionState, ActionValue)>);
+
+impl MockDirectives {
+    /// Sets the state and value for action `A` of the entity.
+    ///
+    /// Replaces the previous directive for this action, if any.
+    pub fn insert<A: InputAction>(
+        &mut self,
+        entity: Entity,
+        state: ActionState,
+        value: impl Into<ActionValue>,
+    ) {
+        self.0
+            .insert((entity, TypeId::of::<A>()), (state, value.into()));
+    }
+
+    /// Removes the directive for action `A` of the entity, returning it to reading its inputs.
+    pub fn remove<A: InputAction>(&mut self, entity: Entity) -> Option<(ActionState, ActionValue)> {
+        self.0.remove(&(entity, TypeId::of::<A>()))
+    }
+
+    /// Removes all directives.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns an iterator over entities, action type IDs, states and values.
+    pub(crate) fn iter(
+        &self,
+    ) -> impl Iterator<Item = (Entity, TypeId, ActionState, ActionValue)> + '_ {
+        self.0
+            .iter()
+            .map(|(&(entity, type_id), &(state, value))| (entity, type_id, state, value))
+    }
+}
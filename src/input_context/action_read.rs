@@ -0,0 +1,74 @@
+use std::{any, marker::PhantomData};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{
+    context_instance::ActionData, input_action::InputAction, ContextInstances, InputContext,
+};
+
+/// System parameter to read [`ActionData`] of action `A` from context `C`.
+///
+/// A convenience wrapper around [`ContextInstances`] that avoids
+/// repeated lookups in systems that poll the state instead of using observers.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// fn jump(jump: ActionRead<Player, Jump>) {
+///     let action = jump.single();
+///     if action.events().contains(ActionEvents::STARTED) {
+///         info!("jumped with value `{:?}`", action.value());
+///     }
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// # impl InputContext for Player {
+/// # fn context_instance(_world: &World, _entity: Entity) -> ContextInstance { Default::default() }
+/// # }
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Jump;
+/// ```
+#[derive(SystemParam)]
+pub struct ActionRead<'w, 's, C: InputContext, A: InputAction> {
+    instances: Res<'w, ContextInstances>,
+    entities: Query<'w, 's, Entity, With<C>>,
+    marker: PhantomData<A>,
+}
+
+impl<C: InputContext, A: InputAction> ActionRead<'_, '_, C, A> {
+    /// Returns the action data for the entity, if it exists.
+    pub fn get(&self, entity: Entity) -> Option<&ActionData> {
+        self.instances
+            .get_context::<C>(entity)
+            .and_then(|ctx| ctx.get_action::<A>())
+    }
+
+    /// Returns the action data if there is only a single entity with context `C`.
+    ///
+    /// For panicking version see [`Self::single`].
+    pub fn get_single(&self) -> Option<&ActionData> {
+        let entity = self.entities.get_single().ok()?;
+        self.get(entity)
+    }
+
+    /// Returns the action data for the only entity with context `C`.
+    ///
+    /// For non-panicking version see [`Self::get_single`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of entities with context `C` is not exactly one
+    /// or if the action `A` was not bound.
+    pub fn single(&self) -> &ActionData {
+        self.get_single().unwrap_or_else(|| {
+            panic!(
+                "exactly one entity with context `{}` and bound action `{}` should exist",
+                any::type_name::<C>(),
+                any::type_name::<A>()
+            )
+        })
+    }
+}
@@ -0,0 +1,48 @@
+//! Run conditions to gate systems on action states.
+//!
+//! All conditions use [`ActionRead::get_single`], so they expect a single entity
+//! with context `C` and return `false` otherwise.
+
+use super::{
+    action_read::ActionRead, context_instance::ActionState, events::ActionEvents,
+    input_action::InputAction, InputContext,
+};
+
+/// Returns a run condition that is `true` while action `A` from context `C` is in [`ActionState::Fired`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// # let mut app = App::new();
+/// app.add_systems(Update, shoot.run_if(action_fired::<Player, Fire>()));
+/// # fn shoot() {}
+/// # #[derive(Component)]
+/// # struct Player;
+/// # impl InputContext for Player {
+/// # fn context_instance(_world: &World, _entity: Entity) -> ContextInstance { Default::default() }
+/// # }
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Fire;
+/// ```
+pub fn action_fired<C: InputContext, A: InputAction>(
+) -> impl FnMut(ActionRead<C, A>) -> bool + Clone {
+    |action: ActionRead<C, A>| {
+        action
+            .get_single()
+            .is_some_and(|action| action.state() == ActionState::Fired)
+    }
+}
+
+/// Returns a run condition that is `true` when action `A` from context `C` has triggered
+/// [`Started`](super::events::Started) during the last update.
+pub fn action_started<C: InputContext, A: InputAction>(
+) -> impl FnMut(ActionRead<C, A>) -> bool + Clone {
+    |action: ActionRead<C, A>| {
+        action
+            .get_single()
+            .is_some_and(|action| action.events().contains(ActionEvents::STARTED))
+    }
+}
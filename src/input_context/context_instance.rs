@@ -2,8 +2,12 @@ mod trigger_tracker;
 
 use std::{
     any::{self, TypeId},
-    cmp::Ordering,
+    borrow::Cow,
+    cmp::{Ordering, Reverse},
+    collections::VecDeque,
     fmt::Debug,
+    mem,
+    ops::AddAssign,
 };
 
 use bevy::{
@@ -12,11 +16,13 @@ use bevy::{
 };
 
 use super::{
-    events::{ActionEvents, Canceled, Completed, Fired, Ongoing, Started},
+    action_mock::ActionMock,
+    events::{ActionEvents, AnyActionEvent, Canceled, Completed, Fired, Ongoing, Started},
     input_action::{Accumulation, ActionOutput, InputAction},
     input_bind::{InputBind, InputBindSet},
     input_condition::{InputCondition, InputConditionSet},
     input_modifier::{InputModifier, InputModifierSet},
+    input_setting::InputSettings,
 };
 use crate::{
     action_value::{ActionValue, ActionValueDim},
@@ -30,6 +36,7 @@ use trigger_tracker::TriggerTracker;
 /// Instance for [`InputContext`](super::InputContext).
 ///
 /// Stores [`InputAction`]s and evaluates their [`ActionState`] in the order they are bound.
+/// Actions derived with [`ActionBind::derive_from`] are evaluated after all others.
 ///
 /// Each action can have multiple associated [`Input`]s, any of which can trigger the action.
 ///
@@ -48,53 +55,108 @@ use trigger_tracker::TriggerTracker;
 /// 5. Set the final [`ActionState`] based on the results.
 ///    Final value be converted into [`InputAction::Output`] using [`ActionValue::convert`].
 ///
-/// New instances won't react to currently held inputs until they are released.
+/// By default, new instances react to currently held inputs immediately.
+/// Actions with [`InputAction::REQUIRE_RESET`] won't react to currently held inputs until they are released.
 /// This prevents unintended behavior where switching contexts using the same key
 /// could cause an immediate switch back, as buttons are rarely pressed for only a single frame.
+/// It can be overridden per instance with [`ActionBind::with_require_reset`].
 ///
 /// [`ActionState`]: super::context_instance::ActionState
-#[derive(Default)]
 pub struct ContextInstance {
     gamepad: GamepadDevice,
+    keyboard_mouse: bool,
+    touch: bool,
     action_binds: Vec<ActionBind>,
-    actions: ActionsData,
+    /// Indices of [`Self::action_binds`] keyed by action type IDs for constant time lookups.
+    bind_indices: HashMap<TypeId, usize>,
+    actions: ActionsData<'static>,
 }
 
 impl ContextInstance {
     /// Associates context with gamepad.
     ///
     /// By default it's [`GamepadDevice::Any`].
+    /// Use [`GamepadDevice::None`] to ignore all gamepads.
     pub fn set_gamepad(&mut self, gamepad: impl Into<GamepadDevice>) {
         self.gamepad = gamepad.into();
     }
 
+    /// Enables or disables reading keyboard and mouse input.
+    ///
+    /// Since keyboard and mouse are global, all contexts read them by default.
+    /// Disable it for contexts that should be controlled only by a gamepad,
+    /// such as a gamepad player in local multiplayer.
+    pub fn set_keyboard_mouse(&mut self, enabled: bool) {
+        self.keyboard_mouse = enabled;
+    }
+
+    /// Enables or disables reading touch input.
+    ///
+    /// Like keyboard and mouse, touch is global, so all contexts read it by default.
+    pub fn set_touch(&mut self, enabled: bool) {
+        self.touch = enabled;
+    }
+
     /// Starts binding an action.
     ///
     /// This method can be called multiple times for the same action to extend its mappings.
+    ///
+    /// # Examples
+    ///
+    /// A single input with a condition can be bound in one call:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Jump>().to(KeyCode::Space.with_conditions(Hold::new(1.0)));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Jump;
+    /// ```
+    ///
+    /// Use [`ActionBind::with_conditions`] instead to apply the condition to all inputs of the action.
     pub fn bind<A: InputAction>(&mut self) -> &mut ActionBind {
         let type_id = TypeId::of::<A>();
-        match self.actions.entry(type_id) {
-            Entry::Occupied(_entry) => self
-                .action_binds
-                .iter_mut()
-                .find(|action_bind| action_bind.type_id == type_id)
-                .expect("actions and bindings should have matching type IDs"),
+        match self.bind_indices.entry(type_id) {
+            Entry::Occupied(entry) => &mut self.action_binds[*entry.get()],
             Entry::Vacant(entry) => {
-                entry.insert(ActionData::new::<A>());
+                entry.insert(self.action_binds.len());
+                self.actions.insert(type_id, ActionData::new::<A>());
                 self.action_binds.push(ActionBind::new::<A>());
                 self.action_binds.last_mut().unwrap()
             }
         }
     }
 
+    /// Removes action `A` with all its bindings and state.
+    ///
+    /// Returns the removed bindings if the action was bound.
+    /// No events are triggered, so the action won't transition to [`ActionState::None`].
+    /// The order of the remaining actions is preserved.
+    pub fn unbind<A: InputAction>(&mut self) -> Option<ActionBind> {
+        let type_id = TypeId::of::<A>();
+        let index = self.bind_indices.remove(&type_id)?;
+        self.actions.remove(&type_id);
+        let action_bind = self.action_binds.remove(index);
+        for action_bind in &self.action_binds[index..] {
+            *self
+                .bind_indices
+                .get_mut(&action_bind.type_id)
+                .expect("bindings should have matching indices") -= 1;
+        }
+
+        Some(action_bind)
+    }
+
     /// Returns associated bindings for action `A` if exists.
     ///
     /// For panicking version see [`Self::action_bind`].
     /// For assigning bindings use [`Self::bind`].
     pub fn get_action_bind<A: InputAction>(&self) -> Option<&ActionBind> {
-        self.action_binds
-            .iter()
-            .find(|action_bind| action_bind.type_id == TypeId::of::<A>())
+        self.bind_indices
+            .get(&TypeId::of::<A>())
+            .map(|&index| &self.action_binds[index])
     }
 
     /// Returns associated bindings for action `A`.
@@ -135,16 +197,182 @@ impl ContextInstance {
         })
     }
 
+    /// Returns mutable associated state for action `A` if exists.
+    ///
+    /// For panicking version see [`Self::action_mut`].
+    pub fn get_action_mut<A: InputAction>(&mut self) -> Option<&mut ActionData> {
+        self.actions.get_mut(&TypeId::of::<A>())
+    }
+
+    /// Returns mutable associated state for action `A`.
+    ///
+    /// For non-panicking version see [`Self::get_action_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the action `A` was not bound beforehand.
+    pub fn action_mut<A: InputAction>(&mut self) -> &mut ActionData {
+        self.get_action_mut::<A>().unwrap_or_else(|| {
+            panic!(
+                "action `{}` should be binded before access",
+                any::type_name::<A>()
+            )
+        })
+    }
+
+    /// Returns bindings of all actions that already use the input.
+    ///
+    /// Inputs are compared including their keyboard modifiers.
+    /// Useful for detecting conflicts in rebinding UIs.
+    pub fn binding_conflicts(&self, input: impl Into<Input>) -> impl Iterator<Item = &ActionBind> {
+        let input = input.into();
+        self.action_binds.iter().filter(move |action_bind| {
+            action_bind
+                .bindings
+                .iter()
+                .any(|binding| binding.input == input)
+        })
+    }
+
+    /// Returns names of actions that triggered [`Fired`] since the last update.
+    ///
+    /// See [`ActionBind::with_name`] for details about names.
+    /// Actions are returned in the order they were bound.
+    /// Useful for polling without observers or knowing the action types.
+    pub fn fired_actions(&self) -> impl Iterator<Item = &str> {
+        self.action_binds.iter().filter_map(|action_bind| {
+            let action = self
+                .actions
+                .get(&action_bind.type_id)
+                .expect("actions and bindings should have matching type IDs");
+
+            action
+                .events()
+                .contains(ActionEvents::FIRED)
+                .then_some(&*action_bind.name)
+        })
+    }
+
+    /// Returns a flat snapshot of all actions keyed by their type names.
+    ///
+    /// Useful for integration with systems that expect action states by name,
+    /// such as when migrating from other input crates or for debugging.
+    /// For regular access prefer [`Self::action`].
+    pub fn snapshot(&self) -> HashMap<&'static str, (ActionState, ActionValue)> {
+        self.action_binds
+            .iter()
+            .map(|action_bind| {
+                let action = self
+                    .actions
+                    .get(&action_bind.type_id)
+                    .expect("actions and bindings should have matching type IDs");
+
+                (action_bind.action_name, (action.state(), action.value()))
+            })
+            .collect()
+    }
+
+    /// Returns actions keyed by type ID.
+    pub(super) fn actions(&self) -> impl Iterator<Item = (TypeId, ActionData)> + '_ {
+        self.actions
+            .iter()
+            .map(|(&type_id, &action)| (type_id, action))
+    }
+
+    /// Returns `true` if any condition reads actions of other entities.
+    ///
+    /// See [`InputCondition::reads_entity_actions`].
+    pub(super) fn reads_entity_actions(&self) -> bool {
+        self.action_binds
+            .iter()
+            .any(|action_bind| action_bind.reads_entity_actions)
+    }
+
     pub(super) fn update(
         &mut self,
         commands: &mut Commands,
         reader: &mut InputReader,
         time: &Time<Virtual>,
+        shared: &SharedData,
         entity: Entity,
-    ) {
-        reader.set_gamepad(self.gamepad);
-        for action_bind in &mut self.action_binds {
-            action_bind.update(commands, reader, &mut self.actions, time, entity);
+    ) -> UpdateStats {
+        let mut stats = UpdateStats::default();
+        let mut actions = ActionsData {
+            shared: Some(shared),
+            ..mem::take(&mut self.actions)
+        };
+        reader.set_gamepad(self.gamepad.clone());
+        reader.set_keyboard_mouse(self.keyboard_mouse);
+        reader.set_touch(self.touch);
+
+        let mut fired_mod_inputs = Vec::new();
+        let mut blocked_actions = Vec::new();
+        for action_bind in self
+            .action_binds
+            .iter_mut()
+            .filter(|action_bind| action_bind.derive.is_none())
+        {
+            if action_bind.block_by_mod_keys {
+                blocked_actions.push(action_bind);
+                continue;
+            }
+
+            action_bind.update(commands, reader, &mut actions, time, &mut stats, entity);
+            action_bind.collect_fired_mod_inputs(&actions, &mut fired_mod_inputs);
+        }
+
+        // Evaluate actions that can be blocked after the others, starting from the ones with more modifiers.
+        blocked_actions.sort_by_key(|action_bind| Reverse(action_bind.max_mod_keys()));
+        for action_bind in blocked_actions {
+            action_bind.block_by_mod_keys(&fired_mod_inputs);
+            action_bind.update(commands, reader, &mut actions, time, &mut stats, entity);
+            action_bind.collect_fired_mod_inputs(&actions, &mut fired_mod_inputs);
+        }
+
+        // Evaluate derived actions last to let them read the fresh state of their sources.
+        for action_bind in self
+            .action_binds
+            .iter_mut()
+            .filter(|action_bind| action_bind.derive.is_some())
+        {
+            action_bind.update(commands, reader, &mut actions, time, &mut stats, entity);
+        }
+
+        self.actions.actions = actions.actions;
+
+        stats
+    }
+
+    /// Overrides state and value of the action with the given type ID for the next update.
+    ///
+    /// Returns `false` if the action isn't bound.
+    pub(super) fn apply_directive(
+        &mut self,
+        type_id: TypeId,
+        state: ActionState,
+        value: ActionValue,
+    ) -> bool {
+        let Some(&index) = self.bind_indices.get(&type_id) else {
+            return false;
+        };
+
+        self.action_binds[index].directive = Some((state, value));
+        true
+    }
+
+    /// Logs a warning for each binding that can't output a non-zero value for its action.
+    ///
+    /// Usually caused by modifiers that move the value to axes missing in the action output,
+    /// like [`SwizzleAxis::YXZ`](super::input_modifier::swizzle_axis::SwizzleAxis::YXZ)
+    /// for a 1-dimensional action.
+    pub(super) fn warn_dead_bindings(&self) {
+        for action_bind in &self.action_binds {
+            for input in action_bind.dead_bindings() {
+                warn!(
+                    "`{input}` for `{}` always outputs zero because of its modifiers",
+                    action_bind.name
+                );
+            }
         }
     }
 
@@ -170,6 +398,19 @@ impl ContextInstance {
     }
 }
 
+impl Default for ContextInstance {
+    fn default() -> Self {
+        Self {
+            gamepad: Default::default(),
+            keyboard_mouse: true,
+            touch: true,
+            action_binds: Default::default(),
+            bind_indices: Default::default(),
+            actions: Default::default(),
+        }
+    }
+}
+
 /// Bindings of [`InputAction`] for [`ContextInstance`].
 ///
 /// These bindings are stored separately from [`ActionsData`] to allow a currently
@@ -177,17 +418,47 @@ impl ContextInstance {
 pub struct ActionBind {
     type_id: TypeId,
     action_name: &'static str,
+    name: Cow<'static, str>,
     consume_input: bool,
     accumulation: Accumulation,
     require_reset: bool,
+    edge_triggered: bool,
+    device_lock: bool,
+    emit_ongoing: bool,
+    block_by_mod_keys: bool,
+    enabled: bool,
+    track_values: bool,
+    bool_threshold: f32,
     dim: ActionValueDim,
 
+    /// Whether any condition reads actions of other entities.
+    ///
+    /// See [`InputCondition::reads_entity_actions`].
+    reads_entity_actions: bool,
+
     modifiers: Vec<Box<dyn InputModifier>>,
     conditions: Vec<Box<dyn InputCondition>>,
     bindings: Vec<InputBind>,
+    mocks: VecDeque<ActionMock>,
+    /// State and value from [`MockDirectives`](super::action_mock::MockDirectives) for the next update.
+    directive: Option<(ActionState, ActionValue)>,
+    derive: Option<DeriveFn>,
+
+    /// Whether the action already fired since the last activation.
+    ///
+    /// Used only if [`InputAction::EDGE_TRIGGERED`] is set.
+    edge_fired: bool,
 
-    /// Consumed inputs during state evaluation.
-    consume_buffer: Vec<Input>,
+    /// Device that activated the action.
+    ///
+    /// Used only if [`InputAction::DEVICE_LOCK`] is set.
+    locked_device: Option<InputDevice>,
+
+    /// Inputs of bindings that determined the state during evaluation.
+    ///
+    /// Consumed if [`InputAction::CONSUME_INPUT`] is set.
+    /// Doesn't include inputs of bindings with [`InputBind::passthrough`].
+    active_inputs: Vec<Input>,
 }
 
 impl ActionBind {
@@ -196,17 +467,71 @@ impl ActionBind {
         Self {
             type_id: TypeId::of::<A>(),
             action_name: any::type_name::<A>(),
+            name: any::type_name::<A>().into(),
             dim: A::Output::DIM,
             consume_input: A::CONSUME_INPUT,
             accumulation: A::ACCUMULATION,
             require_reset: A::REQUIRE_RESET,
+            edge_triggered: A::EDGE_TRIGGERED,
+            device_lock: A::DEVICE_LOCK,
+            emit_ongoing: A::EMIT_ONGOING,
+            block_by_mod_keys: A::BLOCK_BY_MOD_KEYS,
+            enabled: true,
+            track_values: false,
+            bool_threshold: 0.0,
+            reads_entity_actions: false,
             modifiers: Default::default(),
             conditions: Default::default(),
             bindings: Default::default(),
-            consume_buffer: Default::default(),
+            mocks: Default::default(),
+            directive: None,
+            derive: None,
+            edge_fired: false,
+            locked_device: None,
+            active_inputs: Default::default(),
         }
     }
 
+    /// Returns the type ID of the associated action.
+    pub fn action_type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the type name of the associated action.
+    ///
+    /// See also [`Self::name`].
+    pub fn action_name(&self) -> &'static str {
+        self.action_name
+    }
+
+    /// Returns the human-readable name of the action.
+    ///
+    /// Equals to [`Self::action_name`] unless overridden with [`Self::with_name`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets a human-readable name for the action.
+    ///
+    /// Used in logs and returned from [`ContextInstance::fired_actions`] instead of the type name.
+    /// Useful for debugging UIs or when the same action type is used for different purposes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Jump>().with_name("Jump").to(KeyCode::Space);
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Jump;
+    /// ```
+    pub fn with_name(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.name = name.into();
+        self
+    }
+
     /// Returns associated input bindings.
     ///
     /// See also [`Self::to`].
@@ -216,6 +541,10 @@ impl ActionBind {
 
     /// Adds action-level modifiers.
     ///
+    /// Modifiers are applied in the order they were added. Input-level modifiers of each binding
+    /// are applied first, then the values are combined and passed to action-level modifiers.
+    /// Use [`Self::insert_modifiers`] to add a modifier before the existing ones.
+    ///
     /// # Examples
     ///
     /// Single modifier:
@@ -245,13 +574,62 @@ impl ActionBind {
     /// ```
     pub fn with_modifiers(&mut self, set: impl InputModifierSet) -> &mut Self {
         for modifier in set.modifiers() {
-            debug!("adding `{modifier:?}` to `{}`", self.action_name);
+            debug!("adding `{modifier:?}` to `{}`", self.name);
             self.modifiers.push(modifier);
         }
 
         self
     }
 
+    /// Inserts action-level modifiers at the specified position.
+    ///
+    /// Useful when modifiers are added from different places, but one of them needs to run
+    /// earlier, like [`DeadZone`](super::input_modifier::dead_zone::DeadZone) before
+    /// [`Scale`](super::input_modifier::scale::Scale).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of action-level modifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Zoom>()
+    ///     .to(GamepadAxis::RightStickY)
+    ///     .with_modifiers(Scale::splat(0.5))
+    ///     .insert_modifiers(0, DeadZone::default());
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = f32)]
+    /// # struct Zoom;
+    /// ```
+    pub fn insert_modifiers(&mut self, index: usize, set: impl InputModifierSet) -> &mut Self {
+        assert!(
+            index <= self.modifiers.len(),
+            "modifier index {index} for `{}` should not exceed the number of modifiers {}",
+            self.name,
+            self.modifiers.len()
+        );
+
+        for (offset, modifier) in set.modifiers().enumerate() {
+            debug!(
+                "inserting `{modifier:?}` at {} to `{}`",
+                index + offset,
+                self.name
+            );
+            self.modifiers.insert(index + offset, modifier);
+        }
+
+        self
+    }
+
+    /// Returns action-level modifiers in the order they are applied.
+    pub fn modifiers(&self) -> &[Box<dyn InputModifier>] {
+        &self.modifiers
+    }
+
     /// Adds action-level conditions.
     ///
     /// # Examples
@@ -283,7 +661,8 @@ impl ActionBind {
     /// ```
     pub fn with_conditions(&mut self, set: impl InputConditionSet) -> &mut Self {
         for condition in set.conditions() {
-            debug!("adding `{condition:?}` to `{}`", self.action_name);
+            debug!("adding `{condition:?}` to `{}`", self.name);
+            self.reads_entity_actions |= condition.reads_entity_actions();
             self.conditions.push(condition);
         }
 
@@ -394,25 +773,486 @@ impl ActionBind {
     /// ```
     pub fn to(&mut self, set: impl InputBindSet) -> &mut Self {
         for binding in set.bindings() {
-            debug!("adding `{binding:?}` to `{}`", self.action_name);
+            debug!("adding `{binding:?}` to `{}`", self.name);
+            self.reads_entity_actions |= binding
+                .conditions
+                .iter()
+                .any(|condition| condition.reads_entity_actions());
             self.bindings.push(binding);
         }
         self
     }
 
+    /// Overrides [`InputAction::REQUIRE_RESET`] for this instance.
+    ///
+    /// Useful when the same action should ignore held inputs in one context,
+    /// but immediately respect them in another.
+    ///
+    /// Inputs that were held during the removal of a context with
+    /// enabled reset are still ignored until they are released.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Move>()
+    ///     .with_require_reset(false)
+    ///     .to(Cardinal::wasd_keys());
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = Vec2, require_reset = true)]
+    /// # struct Move;
+    /// ```
+    pub fn with_require_reset(&mut self, require_reset: bool) -> &mut Self {
+        self.require_reset = require_reset;
+        self
+    }
+
+    /// Sets the minimum magnitude of the value to be converted into `true` for actions with [`bool`] output.
+    ///
+    /// By default, any non-zero value is converted into `true`, so a slightly pressed analog trigger
+    /// activates a `bool` action. Has no effect on actions with other outputs.
+    /// See [`ActionValue::convert_with_threshold`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Jump>()
+    ///     .with_bool_threshold(0.3)
+    ///     .to(GamepadAxis::RightZ);
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Jump;
+    /// ```
+    pub fn with_bool_threshold(&mut self, threshold: f32) -> &mut Self {
+        self.bool_threshold = threshold;
+        self
+    }
+
+    /// Enables or disables storing raw values of bindings.
+    ///
+    /// When enabled, each update stores the value read from the input of every binding,
+    /// before modifiers and conditions are applied. Read it with [`InputBind::value`].
+    /// Useful for input debugging overlays.
+    ///
+    /// Disabled by default to avoid the overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Move>()
+    ///     .with_value_tracking(true)
+    ///     .to((Cardinal::wasd_keys(), GamepadStick::Left));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = Vec2)]
+    /// # struct Move;
+    /// ```
+    pub fn with_value_tracking(&mut self, track: bool) -> &mut Self {
+        self.track_values = track;
+        if !track {
+            for binding in &mut self.bindings {
+                binding.value = None;
+            }
+        }
+        self
+    }
+
+    /// Enables or disables the action.
+    ///
+    /// Disabled actions don't read their inputs or mocks and stay in [`ActionState::None`].
+    /// Since the transition happens during the next update, disabling an active action triggers
+    /// [`Completed`] or [`Canceled`] like releasing its inputs would.
+    ///
+    /// If [`InputAction::REQUIRE_RESET`] is set, inputs that are held
+    /// when the action is re-enabled are ignored until they are released.
+    ///
+    /// Enabled by default.
+    ///
+    /// # Examples
+    ///
+    /// Disable all menu actions for an entity.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// fn close_menu(mut instances: ResMut<ContextInstances>, player: Single<Entity, With<Menu>>) {
+    ///     let ctx = instances.context_mut::<Menu>(*player);
+    ///     ctx.bind::<Confirm>().set_enabled(false);
+    ///     ctx.bind::<Back>().set_enabled(false);
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Menu;
+    /// # impl InputContext for Menu {
+    /// #     fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+    /// #         ContextInstance::default()
+    /// #     }
+    /// # }
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Confirm;
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Back;
+    /// ```
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        if enabled && !self.enabled {
+            debug!("enabling `{}`", self.name);
+            self.edge_fired = false;
+            self.locked_device = None;
+            if self.require_reset {
+                for binding in &mut self.bindings {
+                    binding.first_activation = true;
+                }
+            }
+        } else if !enabled && self.enabled {
+            debug!("disabling `{}`", self.name);
+        }
+
+        self.enabled = enabled;
+        self
+    }
+
+    /// Returns `true` if the action is enabled.
+    ///
+    /// See also [`Self::set_enabled`].
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Computes the action value from other actions instead of reading inputs.
+    ///
+    /// The function is called with the state of all actions in the context.
+    /// Derived actions are evaluated after all other actions, so the sources are always
+    /// up to date regardless of the bind order. Multiple derived actions are evaluated
+    /// in the order they were bound.
+    ///
+    /// Action-level modifiers and conditions are applied to the returned value.
+    /// Input bindings are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<MovementIntent>().derive_from(|actions| {
+    ///     let movement = actions.action::<Move>().map(|action| action.value().as_axis2d());
+    ///     let dash = actions.action::<Dash>().map(|action| action.value().as_axis2d());
+    ///     movement.unwrap_or_default() + dash.unwrap_or_default()
+    /// });
+    /// ctx.bind::<Move>().to(Cardinal::wasd_keys());
+    /// ctx.bind::<Dash>()
+    ///     .to(KeyCode::ShiftLeft.with_modifiers(Scale::splat(5.0)));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = Vec2)]
+    /// # struct MovementIntent;
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = Vec2)]
+    /// # struct Move;
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = Vec2)]
+    /// # struct Dash;
+    /// ```
+    pub fn derive_from<V: Into<ActionValue>>(
+        &mut self,
+        derive: impl Fn(&ActionsData) -> V + Send + Sync + 'static,
+    ) -> &mut Self {
+        debug!("deriving `{}` from other actions", self.name);
+        self.derive = Some(Box::new(move |actions| derive(actions).into()));
+        self
+    }
+
+    /// Queues a mock that will replace input reading for the action.
+    ///
+    /// Mocks are played in the order they were added.
+    /// See [`ActionMock`] for details.
+    ///
+    /// # Examples
+    ///
+    /// Hold for half a second, release and tap:
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Jump>()
+    ///     .mock(ActionMock::new(
+    ///         ActionState::Fired,
+    ///         true,
+    ///         MockSpan::Duration(Duration::from_secs_f32(0.5)),
+    ///     ))
+    ///     .mock(ActionMock::new(ActionState::None, false, MockSpan::Updates(1)))
+    ///     .mock(ActionMock::new(ActionState::Fired, true, MockSpan::Updates(1)));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Jump;
+    /// ```
+    pub fn mock(&mut self, mock: ActionMock) -> &mut Self {
+        debug!("adding `{mock:?}` to `{}`", self.name);
+        self.mocks.push_back(mock);
+        self
+    }
+
+    /// Removes all queued mocks, returning the action to reading its inputs.
+    pub fn clear_mocks(&mut self) -> &mut Self {
+        self.mocks.clear();
+        self
+    }
+
+    /// Returns the mock that is currently applied to the action.
+    ///
+    /// Returns [`None`] if the action reads its inputs.
+    /// Use [`ActionMock::remaining`] to check how long it will be applied.
+    pub fn active_mock(&self) -> Option<&ActionMock> {
+        self.mocks.front()
+    }
+
+    /// Ignores bindings that share a key with any of the inputs, but have fewer keyboard modifiers.
+    ///
+    /// Used only if [`InputAction::BLOCK_BY_MOD_KEYS`] is set.
+    fn block_by_mod_keys(&mut self, fired_mod_inputs: &[Input]) {
+        for binding in &mut self.bindings {
+            binding.blocked = fired_mod_inputs
+                .iter()
+                .any(|&input| input.extends_mod_keys(binding.input));
+        }
+    }
+
+    /// Adds inputs with keyboard modifiers that fired the action during the last update.
+    fn collect_fired_mod_inputs(&self, actions: &ActionsData, fired_mod_inputs: &mut Vec<Input>) {
+        let action = actions
+            .get(&self.type_id)
+            .expect("actions and bindings should have matching type IDs");
+        if action.state() == ActionState::Fired {
+            fired_mod_inputs.extend(
+                self.active_inputs
+                    .iter()
+                    .filter(|input| !input.mod_keys().is_empty()),
+            );
+        }
+    }
+
+    /// Returns the maximum number of keyboard modifiers among bindings.
+    fn max_mod_keys(&self) -> u32 {
+        self.bindings
+            .iter()
+            .map(|binding| binding.input.mod_keys().bits().count_ones())
+            .max()
+            .unwrap_or_default()
+    }
+
     fn update(
         &mut self,
         commands: &mut Commands,
         reader: &mut InputReader,
         actions: &mut ActionsData,
         time: &Time<Virtual>,
+        stats: &mut UpdateStats,
         entity: Entity,
     ) {
-        trace!("updating action `{}`", self.action_name);
+        trace!("updating action `{}`", self.name);
+        stats.actions += 1;
+        self.active_inputs.clear();
+        let directive = self.directive.take();
+
+        if self.track_values {
+            // Bindings that won't be read during this update shouldn't keep stale values.
+            for binding in &mut self.bindings {
+                binding.value = None;
+            }
+        }
+
+        if !self.enabled {
+            let action = actions
+                .get_mut(&self.type_id)
+                .expect("actions and bindings should have matching type IDs");
+            action.update(time, ActionState::None, ActionValue::zero(self.dim));
+            action.trigger_events(commands, entity);
+            stats.events += self.emitted_events(action.events());
+            return;
+        }
+
+        if let Some((state, value)) = directive {
+            let value = value.convert_with_threshold(self.dim, self.bool_threshold);
+            let action = actions
+                .get_mut(&self.type_id)
+                .expect("actions and bindings should have matching type IDs");
+            action.update(time, state, value);
+            action.trigger_events(commands, entity);
+            stats.events += self.emitted_events(action.events());
+            return;
+        }
+
+        // Mocks with zero span shouldn't drive any update.
+        while let Some(mock) = self.mocks.front().filter(|mock| mock.expired()) {
+            trace!("`{mock:?}` for `{}` expired", self.name);
+            self.mocks.pop_front();
+        }
+
+        if let Some(mock) = self.mocks.front_mut() {
+            let state = mock.state;
+            let value = mock
+                .value
+                .convert_with_threshold(self.dim, self.bool_threshold);
+            if mock.advance(time) {
+                trace!("`{mock:?}` for `{}` expired", self.name);
+                self.mocks.pop_front();
+            }
+
+            let action = actions
+                .get_mut(&self.type_id)
+                .expect("actions and bindings should have matching type IDs");
+            action.update(time, state, value);
+            action.trigger_events(commands, entity);
+            stats.events += self.emitted_events(action.events());
+            return;
+        }
+
+        let mut tracker = match &self.derive {
+            Some(derive) => TriggerTracker::new(derive(actions)),
+            None => self.read_bindings(reader, actions, time, stats),
+        };
+
+        tracker.apply_modifiers(actions, time, &mut self.modifiers);
+        tracker.apply_conditions(actions, time, &mut self.conditions);
+
+        let action = actions
+            .get_mut(&self.type_id)
+            .expect("actions and bindings should have matching type IDs");
+
+        let mut state = tracker.state();
+        let value = tracker
+            .value()
+            .convert_with_threshold(self.dim, self.bool_threshold);
+
+        if self.edge_triggered {
+            match state {
+                ActionState::None => self.edge_fired = false,
+                ActionState::Ongoing => (),
+                ActionState::Fired if self.edge_fired => state = ActionState::Ongoing,
+                ActionState::Fired => self.edge_fired = true,
+            }
+        }
+
+        if self.consume_input && state != ActionState::None {
+            for &input in &self.active_inputs {
+                reader.consume(input);
+            }
+            stats.consumed_inputs += self.active_inputs.len();
+        }
+
+        action.update(time, state, value);
+        if !tracker.events_blocked() {
+            action.trigger_events(commands, entity);
+            stats.events += self.emitted_events(action.events());
+        }
+    }
+
+    /// Returns the number of events that [`ActionData::trigger_events`] emits for the action.
+    fn emitted_events(&self, mut events: ActionEvents) -> usize {
+        if !self.emit_ongoing {
+            events.remove(ActionEvents::ONGOING);
+        }
+        events.iter().count()
+    }
+
+    /// Returns inputs for which input- and action-level modifiers can't output a non-zero value.
+    ///
+    /// See [`InputModifier::nonzero_axes`].
+    fn dead_bindings(&self) -> impl Iterator<Item = Input> + '_ {
+        self.bindings
+            .iter()
+            .filter(|binding| {
+                let input_axes = ActionValue::Axis3D(Vec3::ONE).convert(binding.input.dim());
+                let axes = binding
+                    .modifiers
+                    .iter()
+                    .chain(&self.modifiers)
+                    .fold(input_axes, |axes, modifier| modifier.nonzero_axes(axes));
 
+                !axes.convert(self.dim).as_bool()
+            })
+            .map(|binding| binding.input)
+    }
+
+    /// Reads input bindings and combines their values according to [`Self::accumulation`].
+    ///
+    /// Inputs of the bindings that determined the state are stored in [`Self::active_inputs`].
+    /// If [`Self::device_lock`] is set, reads only bindings from the locked device.
+    fn read_bindings(
+        &mut self,
+        reader: &InputReader,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        stats: &mut UpdateStats,
+    ) -> TriggerTracker {
+        if !self.device_lock {
+            let (tracker, _) = self.read_device_bindings(reader, actions, time, stats, |_| true);
+            return tracker;
+        }
+
+        if let Some(locked_device) = self.locked_device {
+            let (tracker, active_device) =
+                self.read_device_bindings(reader, actions, time, stats, |device| {
+                    device == locked_device
+                });
+            if active_device.is_some() {
+                return tracker;
+            }
+
+            // The locked device was released, read other devices in the same frame.
+            trace!("unlocking `{}` from `{locked_device:?}`", self.name);
+            let (tracker, active_device) =
+                self.read_device_bindings(reader, actions, time, stats, |device| {
+                    device != locked_device
+                });
+            self.locked_device = active_device;
+            return tracker;
+        }
+
+        let (tracker, active_device) =
+            self.read_device_bindings(reader, actions, time, stats, |_| true);
+        self.locked_device = active_device;
+        tracker
+    }
+
+    /// Reads bindings for devices that pass the filter.
+    ///
+    /// Returns the tracker and the device of the binding with the most significant state.
+    fn read_device_bindings(
+        &mut self,
+        reader: &InputReader,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        stats: &mut UpdateStats,
+        filter: impl Fn(InputDevice) -> bool,
+    ) -> (TriggerTracker, Option<InputDevice>) {
         let mut tracker = TriggerTracker::new(ActionValue::zero(self.dim));
+        let mut active_device = None;
+        let mut priority = i32::MIN;
         for binding in &mut self.bindings {
-            let value = reader.value(binding.input);
+            let device = InputDevice::from(binding.input);
+            if !filter(device) || binding.blocked {
+                continue;
+            }
+
+            stats.bindings += 1;
+            let value = if binding.exact_mod_keys {
+                reader.exact_value(binding.input)
+            } else {
+                reader.value(binding.input)
+            };
+            if self.track_values {
+                binding.value = Some(value);
+            }
             if self.require_reset && binding.first_activation {
                 // Ignore until we read zero for this mapping.
                 if value.as_bool() {
@@ -422,10 +1262,26 @@ impl ActionBind {
                 }
             }
 
+            if !value.as_bool()
+                && binding.conditions.is_empty()
+                && binding
+                    .modifiers
+                    .iter()
+                    .all(|modifier| modifier.preserves_zero())
+            {
+                // The binding would be ignored anyway, skip evaluation.
+                continue;
+            }
+
             let mut current_tracker = TriggerTracker::new(value);
             current_tracker.apply_modifiers(actions, time, &mut binding.modifiers);
             current_tracker.apply_conditions(actions, time, &mut binding.conditions);
 
+            if self.dim == ActionValueDim::Bool && self.bool_threshold > 0.0 {
+                // Convert before combining to apply the threshold to each binding value.
+                current_tracker.convert(self.dim, self.bool_threshold);
+            }
+
             let current_state = current_tracker.state();
             if current_state == ActionState::None {
                 // Ignore non-active trackers to allow the action to fire even if all
@@ -434,63 +1290,168 @@ impl ActionBind {
                 continue;
             }
 
-            match current_state.cmp(&tracker.state()) {
+            // On equal states, bindings with a higher priority win.
+            let ordering = current_state
+                .cmp(&tracker.state())
+                .then(binding.priority.cmp(&priority));
+            match ordering {
                 Ordering::Less => (),
                 Ordering::Equal => {
                     tracker.combine(current_tracker, self.accumulation);
-                    if self.consume_input {
-                        self.consume_buffer.push(binding.input);
+                    if !binding.passthrough {
+                        self.active_inputs.push(binding.input);
                     }
                 }
                 Ordering::Greater => {
                     tracker.overwrite(current_tracker);
-                    if self.consume_input {
-                        self.consume_buffer.clear();
-                        self.consume_buffer.push(binding.input);
+                    active_device = Some(device);
+                    priority = binding.priority;
+                    self.active_inputs.clear();
+                    if !binding.passthrough {
+                        self.active_inputs.push(binding.input);
                     }
                 }
             }
         }
 
-        tracker.apply_modifiers(actions, time, &mut self.modifiers);
-        tracker.apply_conditions(actions, time, &mut self.conditions);
+        (tracker, active_device)
+    }
+}
 
-        let action = actions
-            .get_mut(&self.type_id)
-            .expect("actions and bindings should have matching type IDs");
+/// Counters accumulated during an update of [`ContextInstance`].
+///
+/// Used for `InputDiagnosticsPlugin`.
+#[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+pub(crate) struct UpdateStats {
+    /// Number of evaluated actions, including mocked ones.
+    pub(crate) actions: usize,
 
-        let state = tracker.state();
-        let value = tracker.value().convert(self.dim);
+    /// Number of read input bindings.
+    pub(crate) bindings: usize,
 
-        if self.consume_input {
-            if state != ActionState::None {
-                for &input in &self.consume_buffer {
-                    reader.consume(input);
-                }
-            }
-            self.consume_buffer.clear();
-        }
+    /// Number of consumed inputs.
+    pub(crate) consumed_inputs: usize,
 
-        action.update(time, state, value);
-        if !tracker.events_blocked() {
-            action.trigger_events(commands, entity);
+    /// Number of triggered events.
+    pub(crate) events: usize,
+}
+
+impl AddAssign for UpdateStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.actions += rhs.actions;
+        self.bindings += rhs.bindings;
+        self.consumed_inputs += rhs.consumed_inputs;
+        self.events += rhs.events;
+    }
+}
+
+/// Device family of an [`Input`].
+///
+/// Used for [`InputAction::DEVICE_LOCK`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InputDevice {
+    KeyboardMouse,
+    Gamepad,
+    Touch,
+}
+
+impl From<Input> for InputDevice {
+    fn from(input: Input) -> Self {
+        match input {
+            Input::Keyboard { .. }
+            | Input::KeyRepeat { .. }
+            | Input::Character { .. }
+            | Input::MouseButton { .. }
+            | Input::ModKeys(_)
+            | Input::MouseMotion { .. }
+            | Input::MouseWheel { .. }
+            | Input::MousePosition { .. } => Self::KeyboardMouse,
+            Input::GamepadButton(_) | Input::GamepadAxis(_) | Input::GamepadTrigger(_) => {
+                Self::Gamepad
+            }
+            Input::TouchPress { .. } | Input::TouchMotion { .. } => Self::Touch,
         }
     }
 }
 
+/// Function that computes the value of an action from other actions.
+///
+/// See [`ActionBind::derive_from`].
+type DeriveFn = Box<dyn Fn(&ActionsData) -> ActionValue + Send + Sync>;
+
 /// Map for actions to their data.
 ///
 /// Can be accessed from [`InputCondition::evaluate`]
 /// or [`ContextInstances::context`](super::ContextInstances::context).
 #[derive(Default, Deref, DerefMut)]
-pub struct ActionsData(pub HashMap<TypeId, ActionData>);
+pub struct ActionsData<'a> {
+    #[deref]
+    actions: HashMap<TypeId, ActionData>,
+
+    /// Data shared between all instances.
+    ///
+    /// Available only during evaluation.
+    shared: Option<&'a SharedData<'a>>,
+}
 
-impl ActionsData {
+/// Data shared between all context instances during their evaluation.
+pub(super) struct SharedData<'a> {
+    /// Actions of all context instances from the previous update.
+    ///
+    /// Empty if no condition reads them.
+    pub(super) entity_actions: &'a EntityActions,
+
+    pub(super) settings: &'a InputSettings,
+
+    /// Whether any window is focused or there are no windows.
+    pub(super) window_focused: bool,
+}
+
+/// Actions of all context instances, keyed by entity and action type ID.
+pub(super) type EntityActions = HashMap<(Entity, TypeId), ActionData>;
+
+impl ActionsData<'_> {
     /// Returns associated state for action `A`.
     pub fn action<A: InputAction>(&self) -> Option<&ActionData> {
         self.get(&TypeId::of::<A>())
     }
 
+    /// Returns the value of setting `R` registered with
+    /// [`ContextAppExt::add_input_setting`](super::ContextAppExt::add_input_setting).
+    ///
+    /// Available only during evaluation of modifiers and conditions.
+    /// Returns [`None`] if the setting is not registered or the resource doesn't exist.
+    pub fn setting<R: Resource>(&self) -> Option<bool> {
+        self.shared?.settings.get::<R>()
+    }
+
+    /// Returns `true` if any window is focused or if there are no windows.
+    ///
+    /// Available only during evaluation of modifiers and conditions.
+    pub fn window_focused(&self) -> bool {
+        self.shared.is_none_or(|shared| shared.window_focused)
+    }
+
+    /// Returns the state of action `A` from a context instance of another entity.
+    ///
+    /// See [`Self::entity_action_by_id`] for details.
+    pub fn entity_action<A: InputAction>(&self, entity: Entity) -> Option<&ActionData> {
+        self.entity_action_by_id(entity, TypeId::of::<A>())
+    }
+
+    /// Returns the state of an action by its type ID from a context instance of another entity.
+    ///
+    /// Available only during evaluation of modifiers and conditions.
+    /// Since instances are evaluated one after another, it contains states from
+    /// the previous update. This makes the result independent of the evaluation order.
+    ///
+    /// Actions of other entities are collected only if any condition returns `true`
+    /// from [`InputCondition::reads_entity_actions`].
+    pub fn entity_action_by_id(&self, entity: Entity, type_id: TypeId) -> Option<&ActionData> {
+        self.shared?.entity_actions.get(&(entity, type_id))
+    }
+
     /// Inserts a state for action `A`.
     ///
     /// Returns previously associated state if present.
@@ -509,6 +1470,9 @@ pub struct ActionData {
     value: ActionValue,
     elapsed_secs: f32,
     fired_secs: f32,
+    fire_count: u32,
+    trigger_count: u32,
+    persist_timing: bool,
     trigger_events: fn(&Self, &mut Commands, Entity),
 }
 
@@ -524,6 +1488,9 @@ impl ActionData {
             value: ActionValue::zero(A::Output::DIM),
             elapsed_secs: 0.0,
             fired_secs: 0.0,
+            fire_count: 0,
+            trigger_count: 0,
+            persist_timing: A::PERSIST_TIMING,
             trigger_events: Self::trigger_events_typed::<A>,
         }
     }
@@ -537,12 +1504,17 @@ impl ActionData {
     ) {
         match self.state {
             ActionState::None => {
-                self.elapsed_secs = 0.0;
-                self.fired_secs = 0.0;
+                // Non-empty events mean that the action has just transitioned to `None`.
+                if !self.persist_timing || self.events.is_empty() {
+                    self.elapsed_secs = 0.0;
+                    self.fired_secs = 0.0;
+                }
             }
             ActionState::Ongoing => {
                 self.elapsed_secs += time.delta_secs();
-                self.fired_secs = 0.0;
+                if !self.persist_timing {
+                    self.fired_secs = 0.0;
+                }
             }
             ActionState::Fired => {
                 self.elapsed_secs += time.delta_secs();
@@ -550,6 +1522,13 @@ impl ActionData {
             }
         }
 
+        if state == ActionState::Fired && self.state != ActionState::Fired {
+            self.fire_count = self.fire_count.wrapping_add(1);
+            self.trigger_count = self.trigger_count.wrapping_add(1);
+        } else if state == ActionState::None {
+            self.trigger_count = 0;
+        }
+
         self.events = ActionEvents::new(self.state, state);
         self.state = state;
         self.value = value.into();
@@ -565,6 +1544,10 @@ impl ActionData {
     /// A typed version of [`Self::trigger_events`].
     fn trigger_events_typed<A: InputAction>(&self, commands: &mut Commands, entity: Entity) {
         for (_, event) in self.events.iter_names() {
+            if event == ActionEvents::ONGOING && !A::EMIT_ONGOING {
+                continue;
+            }
+
             match event {
                 ActionEvents::STARTED => {
                     trigger_and_log::<A, _>(
@@ -625,6 +1608,29 @@ impl ActionData {
                 _ => unreachable!("iteration should yield only named flags"),
             }
         }
+
+        if A::EMIT_UNTYPED {
+            let mut events = self.events;
+            if !A::EMIT_ONGOING {
+                events.remove(ActionEvents::ONGOING);
+            }
+
+            if !events.is_empty() {
+                trigger_and_log::<A, _>(
+                    commands,
+                    entity,
+                    AnyActionEvent {
+                        type_id: TypeId::of::<A>(),
+                        action_name: any::type_name::<A>(),
+                        events,
+                        value: self.value,
+                        state: self.state,
+                        fired_secs: self.fired_secs,
+                        elapsed_secs: self.elapsed_secs,
+                    },
+                );
+            }
+        }
     }
 
     /// Returns the current state.
@@ -651,6 +1657,33 @@ impl ActionData {
     pub fn fired_secs(&self) -> f32 {
         self.fired_secs
     }
+
+    /// Number of times the action entered [`ActionState::Fired`] state.
+    ///
+    /// Continuous [`ActionState::Fired`] counts as a single activation.
+    /// Useful for streaks or combos without a separate counter.
+    ///
+    /// See also [`Self::reset_fire_count`].
+    pub fn fire_count(&self) -> u32 {
+        self.fire_count
+    }
+
+    /// Number of times the action entered [`ActionState::Fired`] state
+    /// since it left [`ActionState::None`].
+    ///
+    /// Unlike [`Self::fire_count`], resets to zero when the action returns to [`ActionState::None`].
+    /// Useful for combo counters with conditions that alternate between [`ActionState::Ongoing`]
+    /// and [`ActionState::Fired`], such as [`Pulse`](super::input_condition::pulse::Pulse).
+    pub fn trigger_count(&self) -> u32 {
+        self.trigger_count
+    }
+
+    /// Resets [`Self::fire_count`] to zero.
+    ///
+    /// Obtain mutable access with [`ContextInstance::action_mut`].
+    pub fn reset_fire_count(&mut self) {
+        self.fire_count = 0;
+    }
 }
 
 fn trigger_and_log<A, E: Event + Debug>(commands: &mut Commands, entity: Entity, event: E) {
@@ -667,6 +1700,7 @@ fn trigger_and_log<A, E: Event + Debug>(commands: &mut Commands, entity: Entity,
 ///
 /// See also [`ActionEvents`].
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActionState {
     /// Condition is not triggered.
     #[default]
@@ -682,9 +1716,19 @@ pub enum ActionState {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use bevy_enhanced_input_macros::InputAction;
 
     use super::*;
+    #[cfg(any(feature = "builtin_modifiers", feature = "builtin_conditions"))]
+    use crate::input_context::input_bind::InputBindModCond;
+    #[cfg(feature = "builtin_conditions")]
+    use crate::input_context::input_condition::{chord::Chord, hold::Hold};
+    #[cfg(feature = "builtin_modifiers")]
+    use crate::input_context::input_modifier::{
+        negate::Negate, scale::Scale, smooth_nudge::SmoothNudge, swizzle_axis::SwizzleAxis,
+    };
 
     #[test]
     fn bind() {
@@ -697,7 +1741,201 @@ mod tests {
         assert_eq!(action.bindings.len(), 2);
     }
 
+    #[test]
+    fn unbind() {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to(KeyCode::KeyA);
+        ctx.bind::<PersistentAction>().to(KeyCode::KeyB);
+        ctx.bind::<OtherAction>().to(KeyCode::KeyC);
+
+        let action_bind = ctx.unbind::<DummyAction>().unwrap();
+        assert_eq!(action_bind.bindings[0].input, KeyCode::KeyA.into());
+        assert!(ctx.unbind::<DummyAction>().is_none());
+        assert!(ctx.get_action_bind::<DummyAction>().is_none());
+        assert!(ctx.get_action::<DummyAction>().is_none());
+
+        let action_bind = ctx.action_bind::<PersistentAction>();
+        assert_eq!(action_bind.bindings[0].input, KeyCode::KeyB.into());
+        let action_bind = ctx.action_bind::<OtherAction>();
+        assert_eq!(action_bind.bindings[0].input, KeyCode::KeyC.into());
+
+        ctx.bind::<DummyAction>().to(KeyCode::KeyD);
+        let action_bind = ctx.action_bind::<DummyAction>();
+        assert_eq!(action_bind.bindings[0].input, KeyCode::KeyD.into());
+        assert_eq!(
+            ctx.action_binds.last().unwrap().type_id,
+            TypeId::of::<DummyAction>(),
+            "rebound action should be evaluated last"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "builtin_conditions")]
+    fn bind_with_condition() {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>()
+            .to(KeyCode::KeyA.with_conditions(Hold::new(1.0)));
+
+        let action = ctx.action_bind::<DummyAction>();
+        assert!(action.conditions.is_empty());
+        let [binding] = action.bindings() else {
+            panic!("action should have a single binding");
+        };
+        assert_eq!(binding.input, KeyCode::KeyA.into());
+        assert_eq!(binding.conditions.len(), 1);
+        assert!(binding.modifiers.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "builtin_conditions")]
+    fn reads_entity_actions() {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>()
+            .to(KeyCode::KeyA)
+            .with_conditions(Chord::<OtherAction>::default());
+        assert!(!ctx.reads_entity_actions());
+
+        let entity = Entity::from_raw(0);
+        ctx.bind::<OtherAction>()
+            .to(KeyCode::KeyB.with_conditions(Chord::<DummyAction>::default().with_entity(entity)));
+        assert!(ctx.reads_entity_actions());
+    }
+
+    #[test]
+    fn binding_conflicts() {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to((KeyCode::KeyA, KeyCode::KeyB));
+        ctx.bind::<OtherAction>().to((KeyCode::KeyA, KeyCode::KeyC));
+
+        let conflicts: Vec<_> = ctx
+            .binding_conflicts(KeyCode::KeyA)
+            .map(ActionBind::action_type_id)
+            .collect();
+        assert_eq!(
+            conflicts,
+            [TypeId::of::<DummyAction>(), TypeId::of::<OtherAction>()]
+        );
+
+        let conflicts: Vec<_> = ctx
+            .binding_conflicts(KeyCode::KeyC)
+            .map(ActionBind::action_type_id)
+            .collect();
+        assert_eq!(conflicts, [TypeId::of::<OtherAction>()]);
+
+        assert_eq!(ctx.binding_conflicts(KeyCode::KeyD).count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "builtin_modifiers")]
+    fn dead_bindings() {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAxis>().to((
+            KeyCode::KeyA.with_modifiers(SwizzleAxis::YXZ),
+            KeyCode::KeyB.with_modifiers((SwizzleAxis::YXZ, SwizzleAxis::YXZ)),
+            GamepadAxis::LeftStickX.with_modifiers(Scale::splat(0.0)),
+            Input::mouse_motion().with_modifiers(SwizzleAxis::ZYX),
+            Input::mouse_motion().with_modifiers(Negate::all()),
+        ));
+        ctx.bind::<DummyAction>()
+            .to(KeyCode::KeyC)
+            .with_modifiers(SwizzleAxis::ZYX);
+        ctx.bind::<OtherAction>()
+            .to(KeyCode::KeyD.with_modifiers(SwizzleAxis::YXZ))
+            .with_modifiers(SmoothNudge::default());
+
+        let dead: Vec<_> = ctx.action_bind::<DummyAxis>().dead_bindings().collect();
+        assert_eq!(
+            dead,
+            [
+                KeyCode::KeyA.into(),
+                GamepadAxis::LeftStickX.into(),
+                Input::mouse_motion(),
+            ]
+        );
+
+        assert_eq!(
+            ctx.action_bind::<DummyAction>().dead_bindings().count(),
+            0,
+            "`bool` actions should be affected by all axes"
+        );
+
+        assert_eq!(
+            ctx.action_bind::<OtherAction>().dead_bindings().count(),
+            0,
+            "modifiers without analysis should be assumed to output any value"
+        );
+    }
+
+    #[test]
+    fn timing() {
+        let mut time = Time::<Virtual>::default();
+        time.advance_by(Duration::from_secs(1));
+
+        let mut action = ActionData::new::<DummyAction>();
+        action.update(&time, ActionState::Fired, true);
+        action.update(&time, ActionState::Fired, true);
+        assert_eq!(action.elapsed_secs(), 1.0);
+        assert_eq!(action.fired_secs(), 1.0);
+
+        action.update(&time, ActionState::Ongoing, false);
+        assert_eq!(action.elapsed_secs(), 2.0);
+        assert_eq!(action.fired_secs(), 2.0);
+
+        action.update(&time, ActionState::None, false);
+        assert_eq!(action.elapsed_secs(), 3.0);
+        assert_eq!(action.fired_secs(), 0.0);
+
+        action.update(&time, ActionState::Fired, true);
+        assert_eq!(action.elapsed_secs(), 0.0);
+        assert_eq!(action.fired_secs(), 0.0);
+    }
+
+    #[test]
+    fn persist_timing() {
+        let mut time = Time::<Virtual>::default();
+        time.advance_by(Duration::from_secs(1));
+
+        let mut action = ActionData::new::<PersistentAction>();
+        action.update(&time, ActionState::Fired, true);
+        action.update(&time, ActionState::Fired, true);
+        action.update(&time, ActionState::Ongoing, false);
+        assert_eq!(action.elapsed_secs(), 2.0);
+        assert_eq!(action.fired_secs(), 2.0);
+
+        action.update(&time, ActionState::Fired, true);
+        assert_eq!(action.elapsed_secs(), 3.0);
+        assert_eq!(action.fired_secs(), 2.0);
+
+        action.update(&time, ActionState::None, false);
+        action.update(&time, ActionState::Fired, true);
+        assert_eq!(
+            action.elapsed_secs(),
+            4.0,
+            "timings should persist across a single update in `None`"
+        );
+        assert_eq!(action.fired_secs(), 3.0);
+
+        action.update(&time, ActionState::None, false);
+        action.update(&time, ActionState::None, false);
+        action.update(&time, ActionState::Fired, true);
+        assert_eq!(action.elapsed_secs(), 0.0);
+        assert_eq!(action.fired_secs(), 0.0);
+    }
+
     #[derive(Debug, InputAction)]
     #[input_action(output = bool)]
     struct DummyAction;
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool, persist_timing = true)]
+    struct PersistentAction;
+
+    #[cfg(feature = "builtin_modifiers")]
+    #[derive(Debug, InputAction)]
+    #[input_action(output = f32)]
+    struct DummyAxis;
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool)]
+    struct OtherAction;
 }
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use super::{ActionState, ActionsData};
 use crate::{
-    action_value::ActionValue,
+    action_value::{ActionValue, ActionValueDim},
     input_context::{
         input_action::Accumulation,
         input_condition::{ConditionKind, InputCondition},
@@ -64,7 +64,8 @@ impl TriggerTracker {
     ) {
         // Note: No early outs permitted!
         // All conditions must be evaluated to update their internal state/delta time.
-        for condition in conditions {
+        let mut value_override = None;
+        for condition in conditions.iter_mut() {
             let state = condition.evaluate(actions, time, self.value);
             trace!("`{condition:?}` returns state `{state:?}`");
             match condition.kind() {
@@ -87,6 +88,15 @@ impl TriggerTracker {
                     }
                 }
             }
+
+            if let Some(value) = condition.value_override() {
+                trace!("`{condition:?}` overrides value with `{value:?}`");
+                value_override = Some(value);
+            }
+        }
+
+        if let Some(value) = value_override {
+            self.value = value;
         }
     }
 
@@ -112,6 +122,13 @@ impl TriggerTracker {
         }
     }
 
+    /// Converts the value into the specified dimension.
+    ///
+    /// See [`ActionValue::convert_with_threshold`].
+    pub(super) fn convert(&mut self, dim: ActionValueDim, bool_threshold: f32) {
+        self.value = self.value.convert_with_threshold(dim, bool_threshold);
+    }
+
     pub(super) fn value(&self) -> ActionValue {
         self.value
     }
@@ -132,22 +149,28 @@ impl TriggerTracker {
     /// Merges two trackers.
     ///
     /// Preserves the value dimension.
+    /// [`ActionValue::Bool`] values are combined with logical OR regardless of `accumulation`.
     pub(super) fn combine(&mut self, other: Self, accumulation: Accumulation) {
-        let accumulated = match accumulation {
-            Accumulation::MaxAbs => {
-                let mut value = self.value.as_axis3d().to_array();
-                let other_value = other.value.as_axis3d().to_array();
-                for (axis, other_axis) in value.iter_mut().zip(other_value) {
-                    if axis.abs() < other_axis.abs() {
-                        *axis = other_axis;
+        if let ActionValue::Bool(value) = self.value {
+            self.value = (value || other.value.as_bool()).into();
+        } else {
+            let accumulated = match accumulation {
+                Accumulation::MaxAbs => {
+                    let mut value = self.value.as_axis3d().to_array();
+                    let other_value = other.value.as_axis3d().to_array();
+                    for (axis, other_axis) in value.iter_mut().zip(other_value) {
+                        if axis.abs() < other_axis.abs() {
+                            *axis = other_axis;
+                        }
                     }
+                    value.into()
                 }
-                value.into()
-            }
-            Accumulation::Cumulative => self.value.as_axis3d() + other.value.as_axis3d(),
-        };
+                Accumulation::Cumulative => self.value.as_axis3d() + other.value.as_axis3d(),
+            };
+
+            self.value = ActionValue::Axis3D(accumulated).convert(self.value.dim());
+        }
 
-        self.value = ActionValue::Axis3D(accumulated).convert(self.value.dim());
         self.found_explicit |= other.found_explicit;
         self.any_explicit_fired |= other.any_explicit_fired;
         self.found_active |= other.found_active;
@@ -1,9 +1,10 @@
-use std::fmt::Debug;
+use std::{any::TypeId, fmt::Debug};
 
 use bevy::prelude::*;
 use bitflags::bitflags;
 
 use super::{context_instance::ActionState, input_action::InputAction};
+use crate::action_value::ActionValue;
 
 bitflags! {
     /// Bitset with events triggered by updating [`ActionState`] for an action.
@@ -188,6 +189,50 @@ impl<A: InputAction> Clone for Completed<A> {
 
 impl<A: InputAction> Copy for Completed<A> {}
 
+/// Untyped event that triggers alongside the typed events for actions
+/// with [`InputAction::EMIT_UNTYPED`] enabled.
+///
+/// Triggered once per update with all events resulting from the state transition,
+/// so a single observer can react to any action.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// fn log_actions(trigger: Trigger<AnyActionEvent>) {
+///     let event = trigger.event();
+///     if event.events.contains(ActionEvents::FIRED) {
+///         info!("`{}` fired with `{:?}`", event.action_name, event.value);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Event)]
+pub struct AnyActionEvent {
+    /// Type ID of the action.
+    pub type_id: TypeId,
+
+    /// Type name of the action.
+    pub action_name: &'static str,
+
+    /// Events triggered by the transition.
+    ///
+    /// Doesn't include [`ActionEvents::ONGOING`] if [`InputAction::EMIT_ONGOING`] is disabled.
+    pub events: ActionEvents,
+
+    /// Current action value.
+    pub value: ActionValue,
+
+    /// Current action state.
+    pub state: ActionState,
+
+    /// Time that this action has been in [`ActionState::Fired`] state.
+    pub fired_secs: f32,
+
+    /// Total time this action has been in both [`ActionState::Ongoing`] and [`ActionState::Fired`].
+    pub elapsed_secs: f32,
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_enhanced_input_macros::InputAction;
@@ -49,7 +49,9 @@ use crate::action_value::{ActionValue, ActionValueDim};
 /// struct Move;
 /// ```
 ///
-/// Optionally you can pass `consume_input` and/or `accumulation`:
+/// Optionally you can pass `accumulation`, `consume_input`, `require_reset`, `emit_ongoing`,
+/// `edge_triggered`, `device_lock`, `persist_timing`, `block_by_mod_keys` and `emit_untyped` to override
+/// the corresponding associated constants:
 ///
 /// ```
 /// # use bevy::prelude::*;
@@ -78,6 +80,9 @@ pub trait InputAction: Debug + Send + Sync + 'static {
     /// For details, see [`ContextInstance`](super::context_instance::ContextInstance).
     ///
     /// Consuming is global and affect actions in all contexts.
+    /// Consumed inputs are released at the beginning of each update, so after removing the
+    /// context or disabling the action, other actions can read them immediately
+    /// (unless [`Self::REQUIRE_RESET`] is set).
     const CONSUME_INPUT: bool = true;
 
     /// Associated accumulation behavior.
@@ -89,6 +94,67 @@ pub trait InputAction: Debug + Send + Sync + 'static {
     /// This is useful for context switching or layering to prevent actions to immediately
     /// react previously held inputs.
     const REQUIRE_RESET: bool = false;
+
+    /// Whether to trigger [`Ongoing`](super::events::Ongoing) events.
+    ///
+    /// Disabling it avoids triggering events each frame for actions with conditions like
+    /// [`Hold`](super::input_condition::hold::Hold) when you don't observe them.
+    /// The [`ActionState::Ongoing`](super::context_instance::ActionState::Ongoing) state
+    /// and [`ActionEvents::ONGOING`](super::events::ActionEvents::ONGOING) are still available
+    /// from [`ActionData`](super::context_instance::ActionData).
+    const EMIT_ONGOING: bool = true;
+
+    /// Fire only once per activation.
+    ///
+    /// If enabled, continuous [`ActionState::Fired`](super::context_instance::ActionState::Fired)
+    /// will be collapsed into a single one, followed by
+    /// [`ActionState::Ongoing`](super::context_instance::ActionState::Ongoing) until the action
+    /// returns to [`ActionState::None`](super::context_instance::ActionState::None).
+    /// This means that such actions trigger [`Canceled`](super::events::Canceled)
+    /// instead of [`Completed`](super::events::Completed) on release.
+    ///
+    /// Useful for actions that should react once, even with conditions that fire continuously,
+    /// such as [`Hold`](super::input_condition::hold::Hold).
+    const EDGE_TRIGGERED: bool = false;
+
+    /// Respond only to the device family that activated the action until it's released.
+    ///
+    /// Device families are keyboard with mouse, gamepads and touch. For example, once the action
+    /// is activated by a gamepad stick, keyboard bindings are ignored until the stick returns
+    /// to neutral. Prevents jittering when inputs from multiple devices are mixed.
+    const DEVICE_LOCK: bool = false;
+
+    /// Keep timings of [`ActionData`](super::context_instance::ActionData) across brief state changes.
+    ///
+    /// By default, [`ActionData::fired_secs`](super::context_instance::ActionData::fired_secs)
+    /// resets when the action leaves [`ActionState::Fired`](super::context_instance::ActionState::Fired)
+    /// and both timings reset when the action returns to
+    /// [`ActionState::None`](super::context_instance::ActionState::None).
+    /// If enabled, timings survive [`ActionState::Ongoing`](super::context_instance::ActionState::Ongoing)
+    /// and a single update in [`ActionState::None`](super::context_instance::ActionState::None).
+    /// They reset only if the action stays in `None` for longer.
+    ///
+    /// Useful for continuous timers on actions whose conditions briefly flicker.
+    const PERSIST_TIMING: bool = false;
+
+    /// Ignore bindings while a sibling action in the same context fires with the same key
+    /// and additional keyboard modifiers.
+    ///
+    /// For example, if an action bound to `S` has this enabled, it won't be triggered
+    /// while an action bound to `Ctrl + S` is fired. Unlike
+    /// [`BlockBy`](super::input_condition::block_by::BlockBy), this doesn't require
+    /// wiring actions manually.
+    ///
+    /// Actions with this enabled are evaluated after other actions of the context,
+    /// starting from the ones with the most keyboard modifiers in their bindings.
+    const BLOCK_BY_MOD_KEYS: bool = false;
+
+    /// Whether to trigger [`AnyActionEvent`](super::events::AnyActionEvent) in addition to the typed events.
+    ///
+    /// Allows a single observer to react to all actions with this enabled,
+    /// which is useful for generic UI or logging.
+    /// Disabled by default to avoid the overhead for actions that aren't observed this way.
+    const EMIT_UNTYPED: bool = false;
 }
 
 /// Marks a type which can be used as [`InputAction::Output`].
@@ -151,6 +217,8 @@ impl ActionOutput for Vec3 {
 /// Defines how [`ActionValue`] is calculated when multiple inputs are evaluated with the
 /// same most significant [`ActionState`](super::context_instance::ActionState)
 /// (excluding [`ActionState::None`](super::context_instance::ActionState::None)).
+///
+/// Ignored for [`ActionValue::Bool`] actions, which always combine values with logical OR.
 #[derive(Default, Clone, Copy, Debug)]
 pub enum Accumulation {
     /// Cumulatively add the key values for each mapping.
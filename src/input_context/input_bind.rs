@@ -1,10 +1,14 @@
 use std::iter;
 
+#[cfg(feature = "builtin_conditions")]
+use super::input_condition::press::Press;
+#[cfg(feature = "builtin_modifiers")]
+use super::input_modifier::dead_zone::DeadZone;
 use super::{
     input_condition::{InputCondition, InputConditionSet},
     input_modifier::{InputModifier, InputModifierSet},
 };
-use crate::input::Input;
+use crate::{action_value::ActionValue, input::Input};
 
 /// Associated input for [`ActionBind`](super::context_instance::ActionBind).
 #[derive(Debug)]
@@ -13,6 +17,27 @@ pub struct InputBind {
     pub modifiers: Vec<Box<dyn InputModifier>>,
     pub conditions: Vec<Box<dyn InputCondition>>,
 
+    /// Whether the input should be ignored if any keyboard modifiers other than the
+    /// ones assigned to it are pressed.
+    ///
+    /// See [`InputBindModCond::with_exact_mod_keys`].
+    pub exact_mod_keys: bool,
+
+    /// Priority of the binding within the action.
+    ///
+    /// See [`InputBindModCond::with_priority`].
+    pub priority: i32,
+
+    /// Whether the input should stay available for other actions even if the action consumes inputs.
+    ///
+    /// See [`InputBindModCond::with_passthrough`].
+    pub passthrough: bool,
+
+    /// Raw value of the input from the last update.
+    ///
+    /// Stored only if [`ActionBind::with_value_tracking`](super::context_instance::ActionBind::with_value_tracking) is enabled.
+    pub(super) value: Option<ActionValue>,
+
     /// Whether the input output a non-zero value.
     ///
     /// Needed to prevent newly created contexts from reacting to currently
@@ -20,6 +45,12 @@ pub struct InputBind {
     ///
     /// Used only if [`ActionBind`](super::context_instance::ActionBind::require_reset) is set.
     pub(super) first_activation: bool,
+
+    /// Whether the input is ignored because a sibling action fired with the same key
+    /// and more keyboard modifiers.
+    ///
+    /// Used only if [`InputAction::BLOCK_BY_MOD_KEYS`](super::input_action::InputAction::BLOCK_BY_MOD_KEYS) is set.
+    pub(super) blocked: bool,
 }
 
 impl InputBind {
@@ -29,9 +60,24 @@ impl InputBind {
             input: input.into(),
             modifiers: Default::default(),
             conditions: Default::default(),
+            exact_mod_keys: false,
+            priority: 0,
+            passthrough: false,
+            value: None,
             first_activation: true,
+            blocked: false,
         }
     }
+
+    /// Returns the raw value of the input read during the last update,
+    /// before any modifiers and conditions.
+    ///
+    /// Returns `None` if value tracking is disabled or if the binding wasn't read during
+    /// the last update, e.g. because the action is disabled or mocked.
+    /// See [`ActionBind::with_value_tracking`](super::context_instance::ActionBind::with_value_tracking).
+    pub fn value(&self) -> Option<ActionValue> {
+        self.value
+    }
 }
 
 impl<I: Into<Input>> From<I> for InputBind {
@@ -43,12 +89,130 @@ impl<I: Into<Input>> From<I> for InputBind {
 /// A trait to ergonomically add modifiers or conditions to any type that can be converted into a binding.
 pub trait InputBindModCond {
     /// Adds modifiers.
+    ///
+    /// Modifiers are applied in the order they were added, before action-level modifiers.
     #[must_use]
     fn with_modifiers(self, set: impl InputModifierSet) -> InputBind;
 
     /// Adds conditions.
     #[must_use]
     fn with_conditions(self, set: impl InputConditionSet) -> InputBind;
+
+    /// Requires keyboard modifiers to match exactly.
+    ///
+    /// By default, an input only requires its own modifiers to be pressed, so `S` also fires when
+    /// `Ctrl + S` is pressed. With this, any additional modifiers block the input.
+    /// Has no effect on gamepad and touch inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<MoveBack>().to(KeyCode::KeyS.with_exact_mod_keys());
+    /// ctx.bind::<Save>()
+    ///     .to(KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct MoveBack;
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Save;
+    /// ```
+    #[must_use]
+    fn with_exact_mod_keys(self) -> InputBind;
+
+    /// Assigns priority within the action.
+    ///
+    /// When multiple bindings of an action are active with the same
+    /// [`ActionState`](super::context_instance::ActionState), only bindings with the highest
+    /// priority are used. Their values are combined according to
+    /// [`Accumulation`](super::input_action::Accumulation). Bindings with a lower priority
+    /// still contribute when their state is more significant.
+    ///
+    /// By default all bindings have priority 0.
+    ///
+    /// # Examples
+    ///
+    /// Prefer the trigger over the key when both are used:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Throttle>()
+    ///     .to((KeyCode::KeyW, GamepadAxis::RightZ.with_priority(1)));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = f32)]
+    /// # struct Throttle;
+    /// ```
+    #[must_use]
+    fn with_priority(self, priority: i32) -> InputBind;
+
+    /// Leaves the input available for other actions even if the action has
+    /// [`InputAction::CONSUME_INPUT`](super::input_action::InputAction::CONSUME_INPUT) enabled.
+    ///
+    /// Other bindings of the action are still consumed. Since contexts are evaluated
+    /// by their [`InputContext::PRIORITY`](super::InputContext::PRIORITY), this allows
+    /// an action from a higher-priority context to react to the input without
+    /// blocking actions from lower-priority contexts.
+    /// Passthrough inputs also don't block sibling actions with
+    /// [`InputAction::BLOCK_BY_MOD_KEYS`](super::input_action::InputAction::BLOCK_BY_MOD_KEYS).
+    ///
+    /// # Examples
+    ///
+    /// A global screenshot key that shouldn't block gameplay:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Screenshot>()
+    ///     .to((KeyCode::F12.with_passthrough(), KeyCode::PrintScreen));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = bool)]
+    /// # struct Screenshot;
+    /// ```
+    #[must_use]
+    fn with_passthrough(self) -> InputBind;
+
+    /// Adds [`DeadZone`] modifier with the specified lower threshold.
+    ///
+    /// Shortcut for per-input tuning of analog inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut ctx = ContextInstance::default();
+    /// ctx.bind::<Throttle>()
+    ///     .to(GamepadAxis::RightZ.with_dead_zone(0.1));
+    /// # #[derive(Debug, InputAction)]
+    /// # #[input_action(output = f32)]
+    /// # struct Throttle;
+    /// ```
+    #[cfg(feature = "builtin_modifiers")]
+    #[must_use]
+    fn with_dead_zone(self, lower_threshold: f32) -> InputBind
+    where
+        Self: Sized,
+    {
+        self.with_modifiers(DeadZone::default().with_lower_threshold(lower_threshold))
+    }
+
+    /// Adds [`Press`] condition with the specified actuation threshold.
+    ///
+    /// Shortcut for per-input tuning of analog inputs.
+    #[cfg(feature = "builtin_conditions")]
+    #[must_use]
+    fn with_actuation(self, actuation: f32) -> InputBind
+    where
+        Self: Sized,
+    {
+        self.with_conditions(Press::new(actuation))
+    }
 }
 
 impl<T: Into<InputBind>> InputBindModCond for T {
@@ -63,6 +227,24 @@ impl<T: Into<InputBind>> InputBindModCond for T {
         binding.conditions.extend(set.conditions());
         binding
     }
+
+    fn with_exact_mod_keys(self) -> InputBind {
+        let mut binding = self.into();
+        binding.exact_mod_keys = true;
+        binding
+    }
+
+    fn with_priority(self, priority: i32) -> InputBind {
+        let mut binding = self.into();
+        binding.priority = priority;
+        binding
+    }
+
+    fn with_passthrough(self) -> InputBind {
+        let mut binding = self.into();
+        binding.passthrough = true;
+        binding
+    }
 }
 
 /// Represents collection of bindings that could be passed into
@@ -78,7 +260,7 @@ pub trait InputBindSet {
     ///
     /// <div class="warning">
     ///
-    /// Avoid using this with modifiers like [`DeadZone`](super::input_modifier::dead_zone::DeadZone),
+    /// Avoid using this with modifiers like [`DeadZone`],
     /// as this method applies the modifier to each input **individually** rather than to the entire set.
     ///
     /// </div>
@@ -204,3 +386,58 @@ impl<I: InputBindSet, M: InputConditionSet + Clone> InputBindSet for InputBindCo
             .map(move |binding| binding.with_conditions(self.condition_set.clone()))
     }
 }
+
+#[cfg(all(test, feature = "builtin_modifiers", feature = "builtin_conditions"))]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::input_context::context_instance::{ActionState, ActionsData};
+
+    #[test]
+    fn dead_zone() {
+        let mut binding = GamepadAxis::LeftStickX.with_dead_zone(0.1);
+        assert!(binding.conditions.is_empty());
+        assert_eq!(binding.modifiers.len(), 1);
+
+        let actions = ActionsData::default();
+        let time = Time::default();
+        let modifier = &mut binding.modifiers[0];
+        assert_eq!(modifier.apply(&actions, &time, 0.05.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 1.0.into());
+    }
+
+    #[test]
+    fn actuation() {
+        let mut binding = GamepadAxis::LeftZ.with_actuation(0.3);
+        assert!(binding.modifiers.is_empty());
+        assert_eq!(binding.conditions.len(), 1);
+
+        let actions = ActionsData::default();
+        let time = Time::default();
+        let condition = &mut binding.conditions[0];
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.2.into()),
+            ActionState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.3.into()),
+            ActionState::Fired
+        );
+    }
+
+    #[test]
+    fn dead_zone_and_actuation() {
+        let mut binding = GamepadAxis::LeftZ.with_dead_zone(0.1).with_actuation(0.3);
+        assert_eq!(binding.modifiers.len(), 1);
+        assert_eq!(binding.conditions.len(), 1);
+
+        let actions = ActionsData::default();
+        let time = Time::default();
+        let value = binding.modifiers[0].apply(&actions, &time, 0.05.into());
+        assert_eq!(
+            binding.conditions[0].evaluate(&actions, &time, value),
+            ActionState::None
+        );
+    }
+}
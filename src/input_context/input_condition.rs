@@ -1,13 +1,41 @@
+#[cfg(feature = "builtin_conditions")]
 pub mod block_by;
+#[cfg(feature = "builtin_conditions")]
+pub mod charge_fire;
+#[cfg(feature = "builtin_conditions")]
 pub mod chord;
+#[cfg(feature = "builtin_conditions")]
+pub mod combo;
+#[cfg(feature = "builtin_conditions")]
 pub mod condition_timer;
+#[cfg(feature = "builtin_conditions")]
+pub mod double_click;
+#[cfg(feature = "builtin_conditions")]
 pub mod hold;
+#[cfg(feature = "builtin_conditions")]
 pub mod hold_and_release;
+#[cfg(feature = "builtin_conditions")]
 pub mod just_press;
+#[cfg(feature = "builtin_conditions")]
+pub mod not;
+#[cfg(feature = "builtin_conditions")]
 pub mod press;
+#[cfg(feature = "builtin_conditions")]
 pub mod pulse;
+#[cfg(feature = "builtin_conditions")]
 pub mod release;
+#[cfg(feature = "builtin_conditions")]
+pub mod release_window;
+#[cfg(feature = "builtin_conditions")]
 pub mod tap;
+#[cfg(feature = "builtin_conditions")]
+pub mod toggle;
+#[cfg(feature = "builtin_conditions")]
+pub mod value_changed;
+#[cfg(feature = "builtin_conditions")]
+pub mod when_entity_action;
+#[cfg(feature = "builtin_conditions")]
+pub mod window_focused;
 
 use std::{fmt::Debug, iter};
 
@@ -27,6 +55,10 @@ pub const DEFAULT_ACTUATION: f32 = 0.5;
 /// Can be applied both to inputs and actions.
 /// See [`ActionBind::with_conditions`](super::context_instance::ActionBind::with_conditions)
 /// and [`InputBindModCond::with_conditions`](super::input_bind::InputBindModCond::with_conditions).
+///
+/// Custom conditions don't need to be registered in the app. They are stored inside bindings,
+/// so to stop using one, rebuild the contexts without it by triggering
+/// [`RebuildInputContexts`](super::RebuildInputContexts).
 pub trait InputCondition: Sync + Send + Debug + 'static {
     /// Returns calculates state.
     ///
@@ -42,6 +74,26 @@ pub trait InputCondition: Sync + Send + Debug + 'static {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Explicit
     }
+
+    /// Returns a value that replaces the input value after the last [`Self::evaluate`].
+    ///
+    /// Called after all conditions are evaluated, so other conditions still receive
+    /// the original value. If multiple conditions return a value, the last one is used.
+    ///
+    /// By default returns [`None`], which keeps the value unchanged.
+    fn value_override(&self) -> Option<ActionValue> {
+        None
+    }
+
+    /// Returns `true` if the condition reads actions of other entities.
+    ///
+    /// Actions of other entities are collected only if any condition returns `true`,
+    /// see [`ActionsData::entity_action_by_id`].
+    ///
+    /// By default returns `false`.
+    fn reads_entity_actions(&self) -> bool {
+        false
+    }
 }
 
 /// Determines how a condition contributes to the final [`ActionState`].
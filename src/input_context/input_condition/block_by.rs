@@ -13,8 +13,11 @@ use crate::{
 
 /// Requires another action to not be fired within the same context.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
 pub struct BlockBy<A: InputAction> {
     /// Action that blocks this condition when active.
+    #[cfg_attr(feature = "serde", serde(skip))]
     marker: PhantomData<A>,
 
     /// Whether to block the state or only the events.
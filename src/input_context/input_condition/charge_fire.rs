@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use super::{condition_timer::ConditionTimer, InputCondition, DEFAULT_ACTUATION};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Returns [`ActionState::Ongoing`] while the input is charging and [`ActionState::Fired`]
+/// continuously once it remained actuated for [`Self::charge`] seconds.
+///
+/// Returns [`ActionState::None`] when the input stops being actuated, which resets the charge.
+/// Useful for charge-and-fire weapons, which would otherwise require combining
+/// [`Hold`](super::hold::Hold) with a separate firing action.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChargeFire {
+    /// How long the input has to be held before firing starts.
+    pub charge: f32,
+
+    /// Time in seconds between each firing after the charge.
+    ///
+    /// By default set to 0.0, which means firing every frame.
+    pub interval: f32,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    timer: ConditionTimer,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fire_count: u32,
+}
+
+impl ChargeFire {
+    #[must_use]
+    pub fn new(charge: f32) -> Self {
+        Self {
+            charge,
+            interval: 0.0,
+            actuation: DEFAULT_ACTUATION,
+            timer: Default::default(),
+            fire_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_interval(mut self, interval: f32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    /// Enables or disables time dilation.
+    #[must_use]
+    pub fn relative_speed(mut self, relative: bool) -> Self {
+        self.timer.relative_speed = relative;
+        self
+    }
+}
+
+impl InputCondition for ChargeFire {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        if !value.is_actuated(self.actuation) {
+            self.timer.reset();
+            self.fire_count = 0;
+            return ActionState::None;
+        }
+
+        self.timer.update(time);
+
+        let next_fire = self.charge + self.interval * self.fire_count as f32;
+        if self.timer.duration() >= next_fire {
+            self.fire_count += 1;
+            ActionState::Fired
+        } else {
+            ActionState::Ongoing
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn charge() {
+        let mut condition = ChargeFire::new(1.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        time.advance_by(Duration::from_millis(500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        for _ in 0..3 {
+            time.advance_by(Duration::from_millis(500));
+            assert_eq!(
+                condition.evaluate(&actions, &time, 1.0.into()),
+                ActionState::Fired,
+            );
+        }
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+            "release should reset the charge"
+        );
+    }
+
+    #[test]
+    fn interval() {
+        let mut condition = ChargeFire::new(1.0).with_interval(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        time.advance_by(Duration::from_secs(1));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired,
+        );
+
+        time.advance_by(Duration::from_millis(250));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_millis(250));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired,
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::{any, marker::PhantomData};
 
 use bevy::prelude::*;
 
-use super::{ConditionKind, InputCondition};
+use super::{ConditionKind, InputCondition, DEFAULT_ACTUATION};
 use crate::{
     action_value::ActionValue,
     input_context::{
@@ -14,15 +14,43 @@ use crate::{
 /// Requires action `A` to be fired within the same context.
 ///
 /// Inherits [`ActionState`] from the specified action.
+///
+/// Can also require an action from a context instance of another entity,
+/// see [`Self::with_entity`].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
 pub struct Chord<A: InputAction> {
+    /// Entity with the context instance that contains the action.
+    ///
+    /// If [`None`], the action is read from the same context.
+    pub entity: Option<Entity>,
+
     /// Required action.
+    #[cfg_attr(feature = "serde", serde(skip))]
     marker: PhantomData<A>,
 }
 
+impl<A: InputAction> Chord<A> {
+    /// Requires action `A` from a context instance of the entity.
+    ///
+    /// Useful for global modifier contexts, for example, a separate entity with
+    /// an action that enables alternative abilities for all players.
+    ///
+    /// Since instances are evaluated one after another, the state is read
+    /// from the previous update, see [`ActionsData::entity_action`].
+    /// So the chorded action reacts one update later regardless of the evaluation order.
+    #[must_use]
+    pub fn with_entity(mut self, entity: Entity) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+}
+
 impl<A: InputAction> Default for Chord<A> {
     fn default() -> Self {
         Self {
+            entity: None,
             marker: PhantomData,
         }
     }
@@ -43,15 +71,131 @@ impl<A: InputAction> InputCondition for Chord<A> {
         _time: &Time<Virtual>,
         _value: ActionValue,
     ) -> ActionState {
-        if let Some(action) = actions.action::<A>() {
+        let action = match self.entity {
+            Some(entity) => actions.entity_action::<A>(entity),
+            None => actions.action::<A>(),
+        };
+
+        if let Some(action) = action {
             // Inherit state from the chorded action.
             action.state()
         } else {
+            match self.entity {
+                Some(entity) => warn_once!(
+                    "action `{}` is not present in context of `{entity}`",
+                    any::type_name::<A>()
+                ),
+                None => warn_once!(
+                    "action `{}` is not present in context",
+                    any::type_name::<A>()
+                ),
+            }
+            ActionState::None
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Implicit
+    }
+    fn reads_entity_actions(&self) -> bool {
+        self.entity.is_some()
+    }
+}
+
+/// Like [`Chord`], but also requires the input and action `A` to be pressed
+/// within [`Self::window`] of each other.
+///
+/// Useful for inputs that should be slammed together, like both gamepad triggers.
+/// Returns [`ActionState::Fired`] while both are held if their presses fall within the window.
+/// Otherwise, both need to be released and pressed again.
+///
+/// Action `A` is considered pressed when it's in [`ActionState::Fired`].
+/// Uses virtual time, so the window is affected by pauses and time scaling.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct ChordTimed<A: InputAction> {
+    /// Maximum time in seconds between the presses.
+    pub window: f32,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    /// Time when the input was pressed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    input_press: Option<f32>,
+
+    /// Time when action `A` was fired.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    action_press: Option<f32>,
+
+    /// Required action.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    marker: PhantomData<A>,
+}
+
+impl<A: InputAction> ChordTimed<A> {
+    #[must_use]
+    pub fn new(window: f32) -> Self {
+        Self {
+            window,
+            actuation: DEFAULT_ACTUATION,
+            input_press: None,
+            action_press: None,
+            marker: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+}
+
+impl<A: InputAction> Clone for ChordTimed<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: InputAction> Copy for ChordTimed<A> {}
+
+impl<A: InputAction> InputCondition for ChordTimed<A> {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        let now = time.elapsed_secs();
+        if value.is_actuated(self.actuation) {
+            self.input_press.get_or_insert(now);
+        } else {
+            self.input_press = None;
+        }
+
+        let Some(action) = actions.action::<A>() else {
             warn_once!(
                 "action `{}` is not present in context",
                 any::type_name::<A>()
             );
-            ActionState::None
+            return ActionState::None;
+        };
+
+        if action.state() == ActionState::Fired {
+            self.action_press.get_or_insert(now);
+        } else {
+            self.action_press = None;
+        }
+
+        match (self.input_press, self.action_press) {
+            (Some(input_press), Some(action_press))
+                if (input_press - action_press).abs() <= self.window =>
+            {
+                ActionState::Fired
+            }
+            _ => ActionState::None,
         }
     }
 
@@ -62,6 +206,8 @@ impl<A: InputAction> InputCondition for Chord<A> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use bevy_enhanced_input_macros::InputAction;
 
     use super::*;
@@ -94,6 +240,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn timed_simultaneous() {
+        let mut condition = ChordTimed::<DummyAction>::new(0.1);
+        let mut time = Time::default();
+        let mut action = ActionData::new::<DummyAction>();
+        action.update(&time, ActionState::Fired, true);
+        let mut actions = ActionsData::default();
+        actions.insert_action::<DummyAction>(action);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Fired,
+        );
+
+        time.advance_by(Duration::from_secs(1));
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Fired,
+            "should keep firing while both are held"
+        );
+    }
+
+    #[test]
+    fn timed_within_window() {
+        let mut condition = ChordTimed::<DummyAction>::new(0.1);
+        let mut time = Time::default();
+        let mut actions = ActionsData::default();
+        actions.insert_action::<DummyAction>(ActionData::new::<DummyAction>());
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::None,
+        );
+
+        time.advance_by(Duration::from_millis(50));
+        let mut action = ActionData::new::<DummyAction>();
+        action.update(&time, ActionState::Fired, true);
+        actions.insert_action::<DummyAction>(action);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Fired,
+        );
+    }
+
+    #[test]
+    fn timed_staggered() {
+        let mut condition = ChordTimed::<DummyAction>::new(0.1);
+        let mut time = Time::default();
+        let mut actions = ActionsData::default();
+        actions.insert_action::<DummyAction>(ActionData::new::<DummyAction>());
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::None,
+        );
+
+        time.advance_by(Duration::from_millis(200));
+        let mut action = ActionData::new::<DummyAction>();
+        action.update(&time, ActionState::Fired, true);
+        actions.insert_action::<DummyAction>(action);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::None,
+        );
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Fired,
+            "should fire after pressing again within the window"
+        );
+    }
+
     #[derive(Debug, InputAction)]
     #[input_action(output = bool)]
     struct DummyAction;
@@ -0,0 +1,412 @@
+use std::any::{self, TypeId};
+
+use bevy::prelude::*;
+
+use super::{condition_timer::ConditionTimer, InputCondition};
+use crate::{
+    action_value::ActionValue,
+    input_context::{
+        context_instance::{ActionState, ActionsData},
+        events::ActionEvents,
+        input_action::InputAction,
+    },
+};
+
+/// Requires actions from [`Self::steps`] to trigger their events in order.
+///
+/// Returns [`ActionState::Ongoing`] while the combo is in progress
+/// and [`ActionState::Fired`] for a single update when the last step is completed.
+///
+/// The combo resets if any step takes longer than its [`ComboStep::trigger_time`],
+/// if an action from another step triggers its events out of order
+/// or if any of [`Self::cancel_actions`] triggers its events.
+///
+/// Actions are referenced by their types and need to be bound in the same context
+/// before the action with this condition, like for [`Chord`](super::chord::Chord).
+/// Usually the condition is assigned to an action without inputs.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// # let mut ctx = ContextInstance::default();
+/// ctx.bind::<Jump>().to(KeyCode::Space);
+/// ctx.bind::<Dash>().to(KeyCode::ShiftLeft);
+/// ctx.bind::<Block>().to(KeyCode::KeyB);
+/// ctx.bind::<AirDash>().with_conditions(
+///     Combo::default()
+///         .with_step(ComboStep::new::<Jump>())
+///         .with_step(ComboStep::new::<Dash>().with_trigger_time(0.3))
+///         .with_cancel(CancelAction::new::<Block>()),
+/// );
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Jump;
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Dash;
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Block;
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct AirDash;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Combo {
+    /// Actions that need to be triggered in order.
+    pub steps: Vec<ComboStep>,
+
+    /// Actions that reset the combo.
+    pub cancel_actions: Vec<CancelAction>,
+
+    /// Index of the step that needs to be completed next.
+    step_index: usize,
+
+    /// Time since the completion of the previous step.
+    timer: ConditionTimer,
+}
+
+impl Combo {
+    /// Adds a step to the end of the combo.
+    #[must_use]
+    pub fn with_step(mut self, step: ComboStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Adds an action that resets the combo.
+    #[must_use]
+    pub fn with_cancel(mut self, cancel_action: CancelAction) -> Self {
+        self.cancel_actions.push(cancel_action);
+        self
+    }
+
+    /// Enables or disables time dilation.
+    #[must_use]
+    pub fn relative_speed(mut self, relative: bool) -> Self {
+        self.timer.relative_speed = relative;
+        self
+    }
+
+    fn reset(&mut self) {
+        self.step_index = 0;
+        self.timer.reset();
+    }
+
+    fn is_canceled(&self, actions: &ActionsData) -> bool {
+        let canceled = self.cancel_actions.iter().any(|cancel_action| {
+            triggered(actions, cancel_action.type_id, cancel_action.action_name)
+                .intersects(cancel_action.events)
+        });
+        if canceled {
+            return true;
+        }
+
+        // Steps that don't match the current action shouldn't trigger until their turn.
+        let current_type_id = self.steps[self.step_index].type_id;
+        self.steps
+            .iter()
+            .filter(|step| step.type_id != current_type_id)
+            .any(|step| triggered(actions, step.type_id, step.action_name).intersects(step.events))
+    }
+}
+
+impl InputCondition for Combo {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        _value: ActionValue,
+    ) -> ActionState {
+        if self.steps.is_empty() {
+            warn_once!("combo has no steps");
+            return ActionState::None;
+        }
+
+        if self.step_index > 0 {
+            self.timer.update(time);
+            if self.timer.duration() > self.steps[self.step_index].trigger_time {
+                trace!("combo timed out at step {}", self.step_index);
+                self.reset();
+            }
+        }
+
+        let step = &self.steps[self.step_index];
+        if triggered(actions, step.type_id, step.action_name).intersects(step.events) {
+            self.step_index += 1;
+            self.timer.reset();
+            if self.step_index == self.steps.len() {
+                self.reset();
+                return ActionState::Fired;
+            }
+        } else if self.is_canceled(actions) {
+            trace!("combo canceled at step {}", self.step_index);
+            self.reset();
+        }
+
+        if self.step_index > 0 {
+            ActionState::Ongoing
+        } else {
+            ActionState::None
+        }
+    }
+}
+
+/// Returns events triggered by the action during the current update.
+fn triggered(actions: &ActionsData, type_id: TypeId, action_name: &str) -> ActionEvents {
+    if let Some(action) = actions.get(&type_id) {
+        action.events()
+    } else {
+        warn_once!("action `{action_name}` is not present in context");
+        ActionEvents::empty()
+    }
+}
+
+/// A step for [`Combo`].
+#[derive(Clone, Copy, Debug)]
+pub struct ComboStep {
+    /// Type ID of the required action.
+    pub type_id: TypeId,
+
+    /// Type name of the required action for logging.
+    pub action_name: &'static str,
+
+    /// Events of the action, any of which complete the step.
+    ///
+    /// By default set to [`ActionEvents::COMPLETED`].
+    pub events: ActionEvents,
+
+    /// Maximum time in seconds since the previous step to complete this step.
+    ///
+    /// Ignored for the first step. By default set to 0.5.
+    pub trigger_time: f32,
+}
+
+impl ComboStep {
+    /// Creates a new step for action `A`.
+    #[must_use]
+    pub fn new<A: InputAction>() -> Self {
+        Self {
+            type_id: TypeId::of::<A>(),
+            action_name: any::type_name::<A>(),
+            events: ActionEvents::COMPLETED,
+            trigger_time: 0.5,
+        }
+    }
+
+    #[must_use]
+    pub fn with_events(mut self, events: ActionEvents) -> Self {
+        self.events = events;
+        self
+    }
+
+    #[must_use]
+    pub fn with_trigger_time(mut self, trigger_time: f32) -> Self {
+        self.trigger_time = trigger_time;
+        self
+    }
+}
+
+/// An action that resets [`Combo`].
+#[derive(Clone, Copy, Debug)]
+pub struct CancelAction {
+    /// Type ID of the action.
+    pub type_id: TypeId,
+
+    /// Type name of the action for logging.
+    pub action_name: &'static str,
+
+    /// Events of the action, any of which reset the combo.
+    ///
+    /// By default set to [`ActionEvents::STARTED`].
+    pub events: ActionEvents,
+}
+
+impl CancelAction {
+    /// Creates a new instance for action `A`.
+    #[must_use]
+    pub fn new<A: InputAction>() -> Self {
+        Self {
+            type_id: TypeId::of::<A>(),
+            action_name: any::type_name::<A>(),
+            events: ActionEvents::STARTED,
+        }
+    }
+
+    #[must_use]
+    pub fn with_events(mut self, events: ActionEvents) -> Self {
+        self.events = events;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_enhanced_input_macros::InputAction;
+
+    use super::*;
+    use crate::input_context::context_instance::ActionData;
+
+    #[test]
+    fn ordered() {
+        let mut condition = combo();
+        let mut actions = actions();
+        let time = time(0.1);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::None
+        );
+
+        complete::<First>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Ongoing
+        );
+
+        clear(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Ongoing
+        );
+
+        complete::<Second>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Fired
+        );
+
+        clear(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::None,
+            "should start over after completion"
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let mut condition = combo();
+        let mut actions = actions();
+        let time = time(0.3);
+
+        complete::<First>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Ongoing
+        );
+
+        clear(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Ongoing
+        );
+
+        complete::<Second>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::None,
+            "should reset after exceeding the trigger time"
+        );
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mut condition = combo();
+        let mut actions = actions();
+        let time = time(0.1);
+
+        complete::<Second>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::None
+        );
+
+        complete::<First>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Ongoing
+        );
+
+        complete::<First>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::None,
+            "repeating the first step should cancel"
+        );
+    }
+
+    #[test]
+    fn cancel_action() {
+        let mut condition = combo().with_cancel(CancelAction::new::<Cancel>());
+        let mut actions = actions();
+        actions.insert_action::<Cancel>(ActionData::new::<Cancel>());
+        let time = time(0.1);
+
+        complete::<First>(&mut actions, &time);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::Ongoing
+        );
+
+        clear(&mut actions, &time);
+        actions
+            .get_mut(&TypeId::of::<Cancel>())
+            .unwrap()
+            .update(&time, ActionState::Fired, true);
+        assert_eq!(
+            condition.evaluate(&actions, &time, ActionValue::Bool(false)),
+            ActionState::None
+        );
+    }
+
+    fn combo() -> Combo {
+        Combo::default()
+            .with_step(ComboStep::new::<First>())
+            .with_step(ComboStep::new::<Second>().with_trigger_time(0.5))
+    }
+
+    fn actions() -> ActionsData<'static> {
+        let mut actions = ActionsData::default();
+        actions.insert_action::<First>(ActionData::new::<First>());
+        actions.insert_action::<Second>(ActionData::new::<Second>());
+        actions
+    }
+
+    fn time(delta_secs: f32) -> Time<Virtual> {
+        let mut time = Time::default();
+        time.advance_by(Duration::from_secs_f32(delta_secs));
+        time
+    }
+
+    /// Resets events for all actions.
+    fn clear(actions: &mut ActionsData, time: &Time<Virtual>) {
+        for action in actions.values_mut() {
+            action.update(time, ActionState::None, false);
+        }
+    }
+
+    /// Presses and releases action `A` to trigger [`ActionEvents::COMPLETED`].
+    fn complete<A: InputAction>(actions: &mut ActionsData, time: &Time<Virtual>) {
+        clear(actions, time);
+        let action = actions.get_mut(&TypeId::of::<A>()).unwrap();
+        action.update(time, ActionState::Fired, true);
+        action.update(time, ActionState::None, false);
+    }
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool)]
+    struct First;
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool)]
+    struct Second;
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool)]
+    struct Cancel;
+}
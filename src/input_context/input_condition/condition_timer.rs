@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 /// Helper for building triggers that have firing conditions governed by elapsed time.
 #[derive(Clone, Copy, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionTimer {
     /// If set to `true`, [`Time::relative_speed`] will be applied to the held duration.
     ///
@@ -63,4 +64,25 @@ mod tests {
         timer.update(&time);
         assert_eq!(timer.duration(), 0.1);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialization() {
+        let mut time = Time::<Virtual>::default();
+        time.advance_by(Duration::from_millis(200));
+
+        let mut timer = ConditionTimer {
+            relative_speed: true,
+            ..Default::default()
+        };
+        timer.update(&time);
+
+        let serialized = ron::to_string(&timer).unwrap();
+        let mut timer: ConditionTimer = ron::from_str(&serialized).unwrap();
+        assert!(timer.relative_speed);
+        assert_eq!(timer.duration(), 0.2);
+
+        timer.update(&time);
+        assert_eq!(timer.duration(), 0.4);
+    }
 }
@@ -0,0 +1,217 @@
+use bevy::prelude::*;
+
+use super::{condition_timer::ConditionTimer, InputCondition, DEFAULT_ACTUATION};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Returns [`ActionState::Fired`] when the input is pressed and released twice
+/// within [`Self::interval`] seconds since the first press.
+///
+/// Returns [`ActionState::Ongoing`] while the sequence is in progress.
+/// If the interval passes while the input is held, the input needs to be released
+/// before a new sequence can start.
+///
+/// Intended for buttons. The cursor position is not tracked, so combine it with a
+/// [`Chord`](super::chord::Chord) or [`BlockBy`](super::block_by::BlockBy) if the cursor
+/// movement between clicks needs to be limited.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleClick {
+    /// Time window in seconds for both clicks.
+    pub interval: f32,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    timer: ConditionTimer,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clicks: u8,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    actuated: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    expired: bool,
+}
+
+impl DoubleClick {
+    #[must_use]
+    pub fn new(interval: f32) -> Self {
+        Self {
+            interval,
+            actuation: DEFAULT_ACTUATION,
+            timer: Default::default(),
+            clicks: 0,
+            actuated: false,
+            expired: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    /// Enables or disables time dilation.
+    #[must_use]
+    pub fn relative_speed(mut self, relative: bool) -> Self {
+        self.timer.relative_speed = relative;
+        self
+    }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        self.clicks = 0;
+    }
+}
+
+impl Default for DoubleClick {
+    /// Creates an instance with the interval similar to the default on most platforms.
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl InputCondition for DoubleClick {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        let last_actuated = self.actuated;
+        self.actuated = value.is_actuated(self.actuation);
+
+        if self.expired {
+            self.expired = self.actuated;
+            return ActionState::None;
+        }
+
+        if self.clicks > 0 || last_actuated {
+            self.timer.update(time);
+            if self.timer.duration() > self.interval {
+                self.reset();
+                self.expired = self.actuated;
+                return ActionState::None;
+            }
+        }
+
+        if !last_actuated && self.actuated && self.clicks == 0 {
+            // Start counting from the first press.
+            self.timer.reset();
+        } else if last_actuated && !self.actuated {
+            self.clicks += 1;
+            if self.clicks == 2 {
+                self.reset();
+                return ActionState::Fired;
+            }
+        }
+
+        if self.clicks > 0 || self.actuated {
+            ActionState::Ongoing
+        } else {
+            ActionState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn quick_clicks() {
+        let mut condition = DoubleClick::new(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+
+        time.advance_by(Duration::from_millis(100));
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::None
+        );
+    }
+
+    #[test]
+    fn slow_clicks() {
+        let mut condition = DoubleClick::new(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+
+        time.advance_by(Duration::from_millis(200));
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::None,
+            "should expire after the interval"
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing,
+            "should start a new sequence"
+        );
+    }
+
+    #[test]
+    fn held() {
+        let mut condition = DoubleClick::new(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+
+        time.advance_by(Duration::from_secs(1));
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::None
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            ActionState::None,
+            "release after expiration shouldn't count as a click"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+    }
+}
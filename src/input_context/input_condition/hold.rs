@@ -12,6 +12,7 @@ use crate::{
 /// Returns [`ActionState::None`] when the input stops being actuated earlier than [`Self::hold_time`] seconds.
 /// May optionally fire once, or repeatedly fire.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hold {
     // How long does the input have to be held to cause trigger.
     pub hold_time: f32,
@@ -24,6 +25,7 @@ pub struct Hold {
 
     timer: ConditionTimer,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     fired: bool,
 }
 
@@ -129,6 +131,39 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialization() {
+        let mut condition = Hold::new(1.0).one_shot(true).relative_speed(true);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        time.advance_by(Duration::from_millis(600));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        let serialized = ron::to_string(&condition).unwrap();
+        let mut condition: Hold = ron::from_str(&serialized).unwrap();
+        assert_eq!(condition.hold_time, 1.0);
+        assert!(condition.one_shot);
+        assert!(condition.timer.relative_speed);
+
+        time.advance_by(Duration::from_millis(300));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+            "should continue from the saved elapsed time"
+        );
+
+        time.advance_by(Duration::from_millis(100));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired,
+        );
+    }
+
     #[test]
     fn one_shot() {
         let mut hold = Hold::new(1.0).one_shot(true);
@@ -145,4 +180,36 @@ mod tests {
             ActionState::None
         );
     }
+
+    #[test]
+    fn analog_actuation() {
+        let mut condition = Hold::new(1.0).with_actuation(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.55.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_millis(500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.45.into()),
+            ActionState::None,
+            "value below actuation should reset the hold"
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::new(0.4, 0.4).into()),
+            ActionState::Ongoing,
+            "actuation should be compared with the magnitude"
+        );
+
+        time.advance_by(Duration::from_secs(1));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.55.into()),
+            ActionState::Fired,
+        );
+    }
 }
@@ -10,7 +10,10 @@ use crate::{
 /// when the input is released after having been actuated for [`Self::hold_time`] seconds.
 ///
 /// Returns [`ActionState::None`] when the input stops being actuated earlier than [`Self::hold_time`] seconds.
+///
+/// Optionally ignores input for [`Self::recovery_time`] seconds after firing.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HoldAndRelease {
     // How long does the input have to be held to cause trigger.
     pub hold_time: f32,
@@ -18,7 +21,19 @@ pub struct HoldAndRelease {
     /// Trigger threshold.
     pub actuation: f32,
 
+    /// Time after firing during which the input is ignored.
+    ///
+    /// If the input is actuated when this time passes, it needs to be released
+    /// before the condition starts tracking holds again. Prevents accidental
+    /// re-triggering when the input is pressed again right after the release.
+    ///
+    /// By default set to 0.0, which disables recovery.
+    pub recovery_time: f32,
+
     timer: ConditionTimer,
+    recovery_timer: ConditionTimer,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    recovering: bool,
 }
 
 impl HoldAndRelease {
@@ -27,7 +42,10 @@ impl HoldAndRelease {
         Self {
             hold_time,
             actuation: DEFAULT_ACTUATION,
+            recovery_time: 0.0,
             timer: Default::default(),
+            recovery_timer: Default::default(),
+            recovering: false,
         }
     }
 
@@ -37,10 +55,17 @@ impl HoldAndRelease {
         self
     }
 
+    #[must_use]
+    pub fn with_recovery_time(mut self, recovery_time: f32) -> Self {
+        self.recovery_time = recovery_time;
+        self
+    }
+
     /// Enables or disables time dilation.
     #[must_use]
     pub fn relative_speed(mut self, relative: bool) -> Self {
         self.timer.relative_speed = relative;
+        self.recovery_timer.relative_speed = relative;
         self
     }
 }
@@ -52,18 +77,29 @@ impl InputCondition for HoldAndRelease {
         time: &Time<Virtual>,
         value: ActionValue,
     ) -> ActionState {
+        let actuated = value.is_actuated(self.actuation);
+        if self.recovering {
+            self.recovery_timer.update(time);
+            if self.recovery_timer.duration() >= self.recovery_time && !actuated {
+                self.recovering = false;
+                self.recovery_timer.reset();
+            }
+            return ActionState::None;
+        }
+
         // Evaluate the updated held duration prior to checking for actuation.
         // This stops us failing to trigger if the input is released on the
         // threshold frame due to held duration being 0.
         self.timer.update(time);
         let held_duration = self.timer.duration();
 
-        if value.is_actuated(self.actuation) {
+        if actuated {
             ActionState::Ongoing
         } else {
             self.timer.reset();
             // Trigger if we've passed the threshold and released.
             if held_duration >= self.hold_time {
+                self.recovering = self.recovery_time > 0.0;
                 ActionState::Fired
             } else {
                 ActionState::None
@@ -106,4 +142,46 @@ mod tests {
             ActionState::None
         );
     }
+
+    #[test]
+    fn recovery() {
+        let mut condition = HoldAndRelease::new(1.0).with_recovery_time(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_secs(1));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::Fired
+        );
+
+        // Pressed again right after firing and held past recovery and hold times.
+        time.advance_by(Duration::from_millis(100));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None
+        );
+
+        time.advance_by(Duration::from_secs(2));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None,
+            "should require release after recovery"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+    }
 }
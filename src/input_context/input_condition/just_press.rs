@@ -10,9 +10,11 @@ use crate::{
 ///
 /// Holding the input will not cause further triggers.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JustPress {
     /// Trigger threshold.
     pub actuation: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
     actuated: bool,
 }
 
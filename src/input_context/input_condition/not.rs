@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use super::{ConditionKind, InputCondition};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Inverts the result of the inner condition.
+///
+/// Returns [`ActionState::Fired`] when the inner condition returns [`ActionState::None`]
+/// and vice versa. [`ActionState::Ongoing`] is replaced with [`Self::ongoing`].
+///
+/// The inner condition is still evaluated each update, so its internal state stays up to date.
+/// Uses the [`ConditionKind`] of the inner condition.
+///
+/// # Examples
+///
+/// Fire while sprint is not held.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// # let mut ctx = ContextInstance::default();
+/// ctx.bind::<Walk>()
+///     .to(KeyCode::ShiftLeft.with_conditions(Not::new(Press::default())));
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = bool)]
+/// # struct Walk;
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Not<C: InputCondition> {
+    /// Condition whose result is inverted.
+    pub condition: C,
+
+    /// State returned when the inner condition returns [`ActionState::Ongoing`].
+    ///
+    /// By default set to [`ActionState::Ongoing`].
+    pub ongoing: ActionState,
+}
+
+impl<C: InputCondition> Not<C> {
+    #[must_use]
+    pub fn new(condition: C) -> Self {
+        Self {
+            condition,
+            ongoing: ActionState::Ongoing,
+        }
+    }
+
+    /// Sets [`Self::ongoing`].
+    #[must_use]
+    pub fn with_ongoing(mut self, ongoing: ActionState) -> Self {
+        self.ongoing = ongoing;
+        self
+    }
+}
+
+impl<C: InputCondition + Default> Default for Not<C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C: InputCondition> InputCondition for Not<C> {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        match self.condition.evaluate(actions, time, value) {
+            ActionState::None => ActionState::Fired,
+            ActionState::Ongoing => self.ongoing,
+            ActionState::Fired => ActionState::None,
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        self.condition.kind()
+    }
+
+    fn reads_entity_actions(&self) -> bool {
+        self.condition.reads_entity_actions()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::input_context::input_condition::{hold::Hold, press::Press};
+
+    #[test]
+    fn inversion() {
+        let mut condition = Not::new(Press::default());
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None
+        );
+    }
+
+    #[test]
+    fn ongoing() {
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        let mut condition = Not::new(Hold::new(1.0));
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::Ongoing
+        );
+
+        let mut condition = Not::new(Hold::new(1.0)).with_ongoing(ActionState::None);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            ActionState::None
+        );
+    }
+}
@@ -9,14 +9,29 @@ use crate::{
 /// Returns [`ActionState::Ongoing`] when input becomes actuated and [`ActionState::Fired`]
 /// each [`Self::interval`] seconds.
 ///
+/// The interval can shrink the longer the input is held, like key repeat in menus
+/// or text editors. See [`Self::with_ramp`].
+///
 /// Note: [`Completed`](crate::input_context::events::Completed) only fires
 /// when the repeat limit is reached or when input is released immediately after being triggered.
 /// Otherwise, [`Canceled`](crate::input_context::events::Canceled) is fired when input is released.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pulse {
     /// Time in seconds between each triggering while input is held.
+    ///
+    /// If [`Self::ramp_time`] is set, used as the initial interval.
     pub interval: f32,
 
+    /// Interval in seconds reached after holding the input for [`Self::ramp_time`].
+    pub min_interval: f32,
+
+    /// Time in seconds over which the interval shrinks linearly
+    /// from [`Self::interval`] to [`Self::min_interval`].
+    ///
+    /// By default set to 0.0, which keeps the interval constant.
+    pub ramp_time: f32,
+
     // Number of times the condition can be triggered (0 means no limit).
     pub trigger_limit: u32,
 
@@ -28,7 +43,12 @@ pub struct Pulse {
 
     timer: ConditionTimer,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     trigger_count: u32,
+
+    /// Held duration at which the next trigger happens after the first one.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    next_trigger: f32,
 }
 
 impl Pulse {
@@ -36,14 +56,25 @@ impl Pulse {
     pub fn new(interval: f32) -> Self {
         Self {
             interval,
+            min_interval: interval,
+            ramp_time: 0.0,
             trigger_limit: 0,
             trigger_on_start: true,
             trigger_count: 0,
             actuation: DEFAULT_ACTUATION,
             timer: Default::default(),
+            next_trigger: 0.0,
         }
     }
 
+    /// Shrinks the interval from [`Self::interval`] to `min_interval` over `ramp_time` seconds of holding.
+    #[must_use]
+    pub fn with_ramp(mut self, min_interval: f32, ramp_time: f32) -> Self {
+        self.min_interval = min_interval;
+        self.ramp_time = ramp_time;
+        self
+    }
+
     #[must_use]
     pub fn with_trigger_limit(mut self, trigger_limit: u32) -> Self {
         self.trigger_limit = trigger_limit;
@@ -68,6 +99,16 @@ impl Pulse {
         self.timer.relative_speed = relative;
         self
     }
+
+    /// Returns the interval after holding the input for the given duration.
+    fn interval_at(&self, held_secs: f32) -> f32 {
+        if self.ramp_time <= 0.0 {
+            return self.interval;
+        }
+
+        let progress = (held_secs / self.ramp_time).min(1.0);
+        self.interval.lerp(self.min_interval, progress)
+    }
 }
 
 impl InputCondition for Pulse {
@@ -81,16 +122,17 @@ impl InputCondition for Pulse {
             self.timer.update(time);
 
             if self.trigger_limit == 0 || self.trigger_count < self.trigger_limit {
-                let trigger_count = if self.trigger_on_start {
-                    self.trigger_count
-                } else {
-                    self.trigger_count + 1
+                let trigger_time = match (self.trigger_count, self.trigger_on_start) {
+                    (0, true) => 0.0,
+                    (0, false) => self.interval,
+                    _ => self.next_trigger,
                 };
 
                 // If the repeat count limit has not been reached.
-                if self.timer.duration() >= self.interval * trigger_count as f32 {
+                if self.timer.duration() >= trigger_time {
                     // Trigger when held duration exceeds the interval threshold.
                     self.trigger_count += 1;
+                    self.next_trigger = trigger_time + self.interval_at(trigger_time);
                     ActionState::Fired
                 } else {
                     ActionState::Ongoing
@@ -158,6 +200,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ramp() {
+        let mut condition = Pulse::new(1.0).with_ramp(0.25, 2.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        let mut counts = [0; 4];
+        for count in &mut counts {
+            for _ in 0..20 {
+                if condition.evaluate(&actions, &time, 1.0.into()) == ActionState::Fired {
+                    *count += 1;
+                }
+                time.advance_by(Duration::from_millis(50));
+            }
+        }
+
+        assert_eq!(counts, [1, 2, 4, 4], "pulses should accelerate while held");
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired,
+        );
+        time.advance_by(Duration::from_millis(500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+            "interval should be restored after release"
+        );
+    }
+
     #[test]
     fn trigger_limit() {
         let mut condition = Pulse::new(1.0).with_trigger_limit(1);
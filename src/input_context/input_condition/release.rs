@@ -8,11 +8,35 @@ use crate::{
 
 /// Returns [`ActionState::Ongoing`]` when the input exceeds the actuation threshold and
 /// [`ActionState::Fired`] once when the input drops back below the actuation threshold.
+///
+/// By default, the value on release is the released input, which is usually zero.
+/// Use [`Self::capture_value`] to report the last actuated value instead.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Release {
     /// Trigger threshold.
     pub actuation: f32,
+
+    /// Whether to replace the value on release with the last actuated value.
+    ///
+    /// Useful for charged actions, where the value before the release is the charge amount
+    /// that should be available in [`Fired`](crate::input_context::events::Fired) and
+    /// [`Completed`](crate::input_context::events::Completed).
+    ///
+    /// When the condition is assigned to an input, the captured value is combined with
+    /// values from other inputs according to the action's
+    /// [`Accumulation`](crate::input_context::input_action::Accumulation). Assign it to the
+    /// action to capture the already accumulated value.
+    ///
+    /// By default set to `false`.
+    pub capture_value: bool,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
     actuated: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_value: Option<ActionValue>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    released_value: Option<ActionValue>,
 }
 
 impl Release {
@@ -20,9 +44,18 @@ impl Release {
     pub fn new(actuation: f32) -> Self {
         Self {
             actuation,
+            capture_value: false,
             actuated: false,
+            last_value: None,
+            released_value: None,
         }
     }
+
+    #[must_use]
+    pub fn capture_value(mut self, capture_value: bool) -> Self {
+        self.capture_value = capture_value;
+        self
+    }
 }
 
 impl Default for Release {
@@ -40,17 +73,28 @@ impl InputCondition for Release {
     ) -> ActionState {
         let previously_actuated = self.actuated;
         self.actuated = value.is_actuated(self.actuation);
+        self.released_value = None;
 
         if self.actuated {
             // Ongoing on hold.
+            self.last_value = Some(value);
             ActionState::Ongoing
         } else if previously_actuated {
             // Fired on release.
+            self.released_value = self.last_value.take();
             ActionState::Fired
         } else {
             ActionState::None
         }
     }
+
+    fn value_override(&self) -> Option<ActionValue> {
+        if self.capture_value {
+            self.released_value
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -75,5 +119,33 @@ mod tests {
             condition.evaluate(&actions, &time, 0.0.into()),
             ActionState::Fired
         );
+        assert_eq!(condition.value_override(), None);
+    }
+
+    #[test]
+    fn capture_value() {
+        let mut condition = Release::default().capture_value(true);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.6.into()),
+            ActionState::Ongoing
+        );
+        assert_eq!(condition.value_override(), None);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.8.into()),
+            ActionState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.2.into()),
+            ActionState::Fired
+        );
+        assert_eq!(condition.value_override(), Some(0.8.into()));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+        assert_eq!(condition.value_override(), None);
     }
 }
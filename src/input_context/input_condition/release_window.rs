@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+
+use super::{condition_timer::ConditionTimer, InputCondition, DEFAULT_ACTUATION};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Returns [`ActionState::Ongoing`] when input becomes actuated and [`ActionState::Fired`]
+/// when the input is released after having been actuated for [`Self::min`] to [`Self::max`] seconds.
+///
+/// Returns [`ActionState::None`] when the input is released earlier than [`Self::min`] seconds
+/// or held longer than [`Self::max`] seconds. In the latter case the input needs to be released
+/// before the condition starts tracking holds again.
+///
+/// Useful for timed-release mechanics, like releasing a bowstring at the right moment.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReleaseWindow {
+    /// Minimum hold time in seconds.
+    pub min: f32,
+
+    /// Maximum hold time in seconds.
+    pub max: f32,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    timer: ConditionTimer,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    expired: bool,
+}
+
+impl ReleaseWindow {
+    #[must_use]
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            actuation: DEFAULT_ACTUATION,
+            timer: Default::default(),
+            expired: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    /// Enables or disables time dilation.
+    #[must_use]
+    pub fn relative_speed(mut self, relative: bool) -> Self {
+        self.timer.relative_speed = relative;
+        self
+    }
+}
+
+impl InputCondition for ReleaseWindow {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        let actuated = value.is_actuated(self.actuation);
+        if self.expired {
+            self.expired = actuated;
+            return ActionState::None;
+        }
+
+        // Update before checking for actuation to count the time until the release.
+        self.timer.update(time);
+        let held_duration = self.timer.duration();
+
+        if actuated {
+            if held_duration > self.max {
+                self.timer.reset();
+                self.expired = true;
+                return ActionState::None;
+            }
+
+            ActionState::Ongoing
+        } else {
+            self.timer.reset();
+            if held_duration >= self.min && held_duration <= self.max {
+                ActionState::Fired
+            } else {
+                ActionState::None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn early_release() {
+        let mut condition = ReleaseWindow::new(1.0, 2.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_millis(500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+    }
+
+    #[test]
+    fn within_window() {
+        let mut condition = ReleaseWindow::new(1.0, 2.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_millis(1500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::Fired
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+            "should start tracking a new hold"
+        );
+    }
+
+    #[test]
+    fn expiration() {
+        let mut condition = ReleaseWindow::new(1.0, 2.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_millis(2500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None,
+            "should cancel when held too long"
+        );
+
+        time.advance_by(Duration::from_millis(100));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None,
+            "should require release after expiration"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Ongoing,
+        );
+    }
+}
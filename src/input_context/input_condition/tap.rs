@@ -11,6 +11,7 @@ use crate::{
 ///
 /// Returns [`ActionState::None`] when the input is actuated more than [`Self::release_time`] seconds.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tap {
     /// Time window within which the action must be released to register as a tap.
     pub release_time: f32,
@@ -19,6 +20,7 @@ pub struct Tap {
     pub actuation: f32,
 
     timer: ConditionTimer,
+    #[cfg_attr(feature = "serde", serde(skip))]
     actuated: bool,
 }
 
@@ -112,4 +114,29 @@ mod tests {
             ActionState::None
         );
     }
+
+    #[test]
+    fn analog_actuation() {
+        let mut condition = Tap::new(1.0).with_actuation(0.5);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.55.into()),
+            ActionState::Ongoing,
+        );
+
+        time.advance_by(Duration::from_millis(500));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.45.into()),
+            ActionState::Fired,
+            "value below actuation should count as release"
+        );
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.45.into()),
+            ActionState::None,
+        );
+    }
 }
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use super::{InputCondition, DEFAULT_ACTUATION};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Flips between [`ActionState::Fired`] and [`ActionState::None`] on each actuation,
+/// like a caps lock key.
+///
+/// Only the transition from below to above the actuation threshold flips the state,
+/// so holding the input will not cause further flips.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Toggle {
+    /// Trigger threshold.
+    pub actuation: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    actuated: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    active: bool,
+}
+
+impl Toggle {
+    #[must_use]
+    pub fn new(actuation: f32) -> Self {
+        Self {
+            actuation,
+            actuated: false,
+            active: false,
+        }
+    }
+
+    /// Returns `true` if the condition is currently latched on.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl Default for Toggle {
+    fn default() -> Self {
+        Self::new(DEFAULT_ACTUATION)
+    }
+}
+
+impl InputCondition for Toggle {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        let previously_actuated = self.actuated;
+        self.actuated = value.is_actuated(self.actuation);
+
+        if self.actuated && !previously_actuated {
+            self.active = !self.active;
+        }
+
+        if self.active {
+            ActionState::Fired
+        } else {
+            ActionState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_context::context_instance::ActionsData;
+
+    #[test]
+    fn toggle() {
+        let mut condition = Toggle::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired,
+            "should toggle on press"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired,
+            "should stay on while held"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::Fired,
+            "should stay on after release"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None,
+            "should toggle off on the next press"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+        assert!(!condition.is_active());
+    }
+
+    #[test]
+    fn below_actuation() {
+        let mut condition = Toggle::new(0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.4.into()),
+            ActionState::None
+        );
+    }
+}
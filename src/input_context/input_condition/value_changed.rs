@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+
+use super::InputCondition;
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Returns [`ActionState::Fired`] when the input magnitude increases by at least
+/// the threshold compared to the previous frame.
+///
+/// Unlike [`Press`](super::press::Press), reacts to the change of the value rather
+/// than to its absolute value. Useful to detect the onset of an analog movement,
+/// such as starting to tilt a stick. Decreasing or constant values return [`ActionState::None`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValueChanged {
+    /// Minimum increase of the magnitude per frame.
+    pub threshold: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    previous: f32,
+}
+
+impl ValueChanged {
+    #[must_use]
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            previous: 0.0,
+        }
+    }
+}
+
+impl InputCondition for ValueChanged {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionState {
+        let magnitude = value.as_axis3d().length();
+        let delta = magnitude - self.previous;
+        self.previous = magnitude;
+
+        if delta >= self.threshold {
+            ActionState::Fired
+        } else {
+            ActionState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jump() {
+        let mut condition = ValueChanged::new(0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::new(0.0, 0.8).into()),
+            ActionState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            ActionState::None,
+            "decrease shouldn't fire"
+        );
+    }
+
+    #[test]
+    fn constant() {
+        let mut condition = ValueChanged::new(0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            ActionState::None
+        );
+    }
+
+    #[test]
+    fn ramp() {
+        let mut condition = ValueChanged::new(0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        for value in [0.2, 0.4, 0.6, 0.8, 1.0] {
+            assert_eq!(
+                condition.evaluate(&actions, &time, value.into()),
+                ActionState::None,
+                "increase below threshold per frame shouldn't fire"
+            );
+        }
+    }
+}
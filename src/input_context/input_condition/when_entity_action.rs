@@ -0,0 +1,89 @@
+use std::any::{self, TypeId};
+
+use bevy::prelude::*;
+
+use super::{ConditionKind, InputCondition};
+use crate::{
+    action_value::ActionValue,
+    input_context::{
+        context_instance::{ActionState, ActionsData},
+        events::ActionEvents,
+        input_action::InputAction,
+    },
+};
+
+/// Requires an action from a context instance of another entity to trigger any of [`Self::events`].
+///
+/// Similar to [`Chord`](super::chord::Chord), but not limited to the same context.
+/// Useful for co-op abilities, for example, when both players need to hold a button.
+///
+/// Reads the state from the previous update, see [`ActionsData::entity_action_by_id`].
+/// Returns [`ActionState::Fired`] if the events match and [`ActionState::None`] otherwise,
+/// including when the entity or the action doesn't exist.
+#[derive(Clone, Copy, Debug)]
+pub struct WhenEntityAction {
+    /// Entity with the context instance that contains the action.
+    pub entity: Entity,
+
+    /// Type ID of the required action.
+    pub type_id: TypeId,
+
+    /// Events of the required action, any of which activate the condition.
+    ///
+    /// By default set to [`ActionEvents::FIRED`].
+    pub events: ActionEvents,
+
+    /// Type name of the required action for logging.
+    action_name: &'static str,
+}
+
+impl WhenEntityAction {
+    /// Creates a new instance that requires action `A` of the entity to fire.
+    #[must_use]
+    pub fn new<A: InputAction>(entity: Entity) -> Self {
+        Self {
+            entity,
+            type_id: TypeId::of::<A>(),
+            events: ActionEvents::FIRED,
+            action_name: any::type_name::<A>(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_events(mut self, events: ActionEvents) -> Self {
+        self.events = events;
+        self
+    }
+}
+
+impl InputCondition for WhenEntityAction {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsData,
+        _time: &Time<Virtual>,
+        _value: ActionValue,
+    ) -> ActionState {
+        let Some(action) = actions.entity_action_by_id(self.entity, self.type_id) else {
+            trace!(
+                "action `{}` is not present in context of `{}`",
+                self.action_name,
+                self.entity
+            );
+            return ActionState::None;
+        };
+
+        if action.events().intersects(self.events) {
+            ActionState::Fired
+        } else {
+            ActionState::None
+        }
+    }
+
+    fn reads_entity_actions(&self) -> bool {
+        true
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Implicit
+    }
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use super::{ConditionKind, InputCondition};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+};
+
+/// Requires any window to be focused.
+///
+/// Useful to stop gameplay actions from triggering while the game is in background,
+/// for example to pause on focus loss. Without windows, the condition always passes.
+///
+/// Doesn't contribute to the state on its own, so it's usually combined with other conditions
+/// or applied at the action level.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowFocused {
+    /// Whether to block the state or only the events.
+    ///
+    /// By default set to false.
+    pub events_only: bool,
+}
+
+impl WindowFocused {
+    /// Block only events.
+    ///
+    /// For details, see [`ConditionKind::Blocker::events_only`].
+    #[must_use]
+    pub fn events_only() -> Self {
+        Self { events_only: true }
+    }
+}
+
+impl InputCondition for WindowFocused {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsData,
+        _time: &Time<Virtual>,
+        _value: ActionValue,
+    ) -> ActionState {
+        if actions.window_focused() {
+            ActionState::Fired
+        } else {
+            ActionState::None
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Blocker {
+            events_only: self.events_only,
+        }
+    }
+}
@@ -1,10 +1,48 @@
+#[cfg(feature = "builtin_modifiers")]
 pub mod accumulate_by;
+#[cfg(feature = "builtin_modifiers")]
+pub mod asymmetric_gain;
+#[cfg(feature = "builtin_modifiers")]
+pub mod clamp_magnitude;
+#[cfg(feature = "builtin_modifiers")]
+pub mod clamp_then_normalize;
+#[cfg(feature = "builtin_modifiers")]
 pub mod dead_zone;
+#[cfg(feature = "builtin_modifiers")]
 pub mod delta_scale;
+#[cfg(feature = "builtin_modifiers")]
+pub mod derivative;
+#[cfg(feature = "builtin_modifiers")]
 pub mod exponential_curve;
+#[cfg(feature = "builtin_modifiers")]
+pub mod lookup_table;
+#[cfg(feature = "builtin_modifiers")]
+pub mod momentum;
+#[cfg(feature = "builtin_modifiers")]
 pub mod negate;
+#[cfg(feature = "builtin_modifiers")]
+pub mod normalize;
+#[cfg(feature = "builtin_modifiers")]
+pub mod notch;
+#[cfg(feature = "builtin_modifiers")]
+pub mod radial_dead_zone;
+#[cfg(feature = "builtin_modifiers")]
+pub mod response_curve;
+#[cfg(feature = "builtin_modifiers")]
 pub mod scale;
+#[cfg(feature = "builtin_modifiers")]
+pub mod scale_by;
+#[cfg(feature = "builtin_modifiers")]
+pub mod setting_negate;
+#[cfg(feature = "builtin_modifiers")]
+pub mod smooth_damp;
+#[cfg(feature = "builtin_modifiers")]
 pub mod smooth_nudge;
+#[cfg(feature = "builtin_modifiers")]
+pub mod stabilize;
+#[cfg(feature = "builtin_modifiers")]
+pub mod stick_response;
+#[cfg(feature = "builtin_modifiers")]
 pub mod swizzle_axis;
 
 use std::{fmt::Debug, iter};
@@ -22,6 +60,10 @@ use crate::action_value::ActionValue;
 /// Can be applied both to inputs and actions.
 /// See [`ActionBind::with_modifiers`](super::context_instance::ActionBind::with_modifiers)
 /// and [`InputBindModCond::with_modifiers`](super::input_bind::InputBindModCond::with_modifiers).
+///
+/// Custom modifiers don't need to be registered in the app. They are stored inside bindings,
+/// so to stop using one, rebuild the contexts without it by triggering
+/// [`RebuildInputContexts`](super::RebuildInputContexts).
 pub trait InputModifier: Sync + Send + Debug + 'static {
     /// Returns pre-processed value.
     ///
@@ -32,6 +74,30 @@ pub trait InputModifier: Sync + Send + Debug + 'static {
         time: &Time<Virtual>,
         value: ActionValue,
     ) -> ActionValue;
+
+    /// Returns which axes can be non-zero after applying the modifier.
+    ///
+    /// Accepts a value in which each axis that can be non-zero is set to 1.0.
+    /// Used to detect modifier chains that always output zero for an action.
+    ///
+    /// By default, assumes that any axis can be non-zero.
+    fn nonzero_axes(&self, _axes: ActionValue) -> ActionValue {
+        ActionValue::Axis3D(Vec3::ONE)
+    }
+
+    /// Returns `true` if the modifier has no internal state and always maps zero to zero.
+    ///
+    /// Used to skip evaluation of idle input bindings without conditions,
+    /// since they can't produce a non-zero value.
+    ///
+    /// Called for each idle binding on every update instead of being cached,
+    /// so the result can depend on the current configuration of the modifier.
+    /// Keep it cheap.
+    ///
+    /// By default returns `false`, so the modifier is always applied.
+    fn preserves_zero(&self) -> bool {
+        false
+    }
 }
 
 /// Represents collection of bindings that could be passed into
@@ -65,3 +131,69 @@ macro_rules! impl_tuple_modifiers {
 }
 
 bevy::utils::all_tuples!(impl_tuple_modifiers, 1, 15, I);
+
+#[cfg(all(test, feature = "builtin_modifiers"))]
+mod tests {
+    use bevy_enhanced_input_macros::InputAction;
+
+    use super::{
+        clamp_magnitude::ClampMagnitude, clamp_then_normalize::ClampThenNormalize,
+        dead_zone::DeadZone, delta_scale::DeltaScale, exponential_curve::ExponentialCurve,
+        lookup_table::LookupTable, negate::Negate, normalize::Normalize,
+        radial_dead_zone::RadialDeadZone, response_curve::ResponseCurve, scale::Scale,
+        scale_by::ScaleBy, setting_negate::SettingNegate, stick_response::StickResponse,
+        swizzle_axis::SwizzleAxis, *,
+    };
+    use crate::input_context::context_instance::{ActionData, ActionState};
+
+    #[test]
+    fn preserves_zero() {
+        let modifiers: [Box<dyn InputModifier>; 15] = [
+            Box::new(ClampMagnitude::default()),
+            Box::new(ClampThenNormalize::default()),
+            Box::new(DeadZone::default()),
+            Box::new(DeltaScale::splat(2.0)),
+            Box::new(ExponentialCurve::splat(2.0)),
+            Box::new(LookupTable::new([0.0, 1.0])),
+            Box::new(Negate::all()),
+            Box::new(Normalize::default()),
+            Box::new(RadialDeadZone::default()),
+            Box::new(ResponseCurve::new([Vec2::ZERO, Vec2::ONE])),
+            Box::new(Scale::splat(2.0)),
+            Box::new(ScaleBy::<DummyAction>::splat(2.0)),
+            Box::new(SettingNegate::<DummySetting>::new(Negate::all())),
+            Box::new(StickResponse::standard()),
+            Box::new(SwizzleAxis::YXZ),
+        ];
+        let time = Time::default();
+        let mut action = ActionData::new::<DummyAction>();
+        action.update(&time, ActionState::Fired, true);
+        let mut actions = ActionsData::default();
+        actions.insert_action::<DummyAction>(action);
+
+        for mut modifier in modifiers {
+            assert!(modifier.preserves_zero(), "{modifier:?}");
+            for value in [
+                false.into(),
+                0.0.into(),
+                Vec2::ZERO.into(),
+                Vec3::ZERO.into(),
+            ] {
+                let new_value: ActionValue = modifier.apply(&actions, &time, value);
+                assert!(!new_value.as_bool(), "{modifier:?} with `{value:?}`");
+            }
+        }
+
+        assert!(!ExponentialCurve::splat(0.0).preserves_zero());
+        assert!(!LookupTable::new([0.5, 1.0]).preserves_zero());
+        assert!(!ResponseCurve::new([Vec2::Y, Vec2::ONE]).preserves_zero());
+        assert!(!StickResponse::new(0.0).preserves_zero());
+    }
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool)]
+    struct DummyAction;
+
+    #[derive(Resource)]
+    struct DummySetting;
+}
@@ -14,11 +14,15 @@ use crate::{
 /// Continuously adds input values together as long as action `A` is [`ActionState::Fired`].
 /// When the action is inactive, it resets the accumulation with the current frame's input value.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
 pub struct AccumulateBy<A: InputAction> {
     /// Action that activates accumulation.
+    #[cfg_attr(feature = "serde", serde(skip))]
     marker: PhantomData<A>,
 
     /// The accumulated value across frames.
+    #[cfg_attr(feature = "serde", serde(skip))]
     value: Vec3,
 }
 
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Scales input by different factors depending on whether it moves away from or toward the center.
+///
+/// Compares the magnitude of the value with the magnitude from the previous evaluation.
+/// When it grows, the value is multiplied by [`Self::outward`], when it shrinks, by [`Self::inward`].
+/// If the magnitude doesn't change, the last used factor is kept to avoid jumps while holding.
+///
+/// Useful for flight sticks that need more gain when pushed and less when returning.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AsymmetricGain {
+    /// Factor applied when the magnitude increases.
+    pub outward: f32,
+
+    /// Factor applied when the magnitude decreases.
+    pub inward: f32,
+
+    /// Magnitude of the value from the previous evaluation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    previous_magnitude: f32,
+
+    /// Whether the last change in magnitude was a decrease.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    moving_inward: bool,
+}
+
+impl AsymmetricGain {
+    #[must_use]
+    pub fn new(outward: f32, inward: f32) -> Self {
+        Self {
+            outward,
+            inward,
+            previous_magnitude: 0.0,
+            moving_inward: false,
+        }
+    }
+}
+
+impl InputModifier for AsymmetricGain {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.apply(_actions, _time, value.into());
+        }
+
+        let value3d = value.as_axis3d();
+        let magnitude = value3d.length();
+        if magnitude > self.previous_magnitude {
+            self.moving_inward = false;
+        } else if magnitude < self.previous_magnitude {
+            self.moving_inward = true;
+        }
+        self.previous_magnitude = magnitude;
+
+        let gain = if self.moving_inward {
+            self.inward
+        } else {
+            self.outward
+        };
+
+        ActionValue::Axis3D(value3d * gain).convert(value.dim())
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outward() {
+        let mut modifier = AsymmetricGain::new(2.0, 0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.25.into()), 0.5.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 1.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, -1.0).into()),
+            (0.0, -2.0).into()
+        );
+    }
+
+    #[test]
+    fn inward() {
+        let mut modifier = AsymmetricGain::new(2.0, 0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        modifier.apply(&actions, &time, 1.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 0.25.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, (-0.2).into()),
+            (-0.1).into()
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        let mut modifier = AsymmetricGain::new(2.0, 0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 2.0.into());
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 2.0.into());
+
+        modifier.apply(&actions, &time, 0.5.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, 0.5.into()),
+            0.25.into(),
+            "should keep the last gain while the magnitude doesn't change"
+        );
+    }
+}
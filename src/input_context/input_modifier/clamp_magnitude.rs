@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Scales the input down to [`Self::max`] if its length exceeds it, preserving the direction.
+///
+/// Unlike [`ClampThenNormalize`](super::clamp_then_normalize::ClampThenNormalize),
+/// doesn't clamp individual axes, so only the overall magnitude is limited.
+/// Useful to prevent diagonal movement from being faster.
+///
+/// See also [`ActionValue::clamp_magnitude`].
+///
+/// [`ActionValue::Bool`] is passed through unchanged.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClampMagnitude {
+    /// Maximum length of the value.
+    ///
+    /// The sign is ignored. By default set to 1.0.
+    pub max: f32,
+}
+
+impl ClampMagnitude {
+    #[must_use]
+    pub fn new(max: f32) -> Self {
+        Self { max }
+    }
+}
+
+impl Default for ClampMagnitude {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl InputModifier for ClampMagnitude {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        value.clamp_magnitude(self.max)
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal() {
+        let mut modifier = ClampMagnitude::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        let value = modifier
+            .apply(&actions, &time, (1.0, 1.0).into())
+            .as_axis2d();
+        assert!((value.length() - 1.0).abs() < f32::EPSILON);
+        assert!((value.to_angle() - Vec2::ONE.to_angle()).abs() < f32::EPSILON);
+
+        let value = modifier
+            .apply(&actions, &time, (2.0, -2.0, 2.0).into())
+            .as_axis3d();
+        assert!((value.length() - 1.0).abs() < f32::EPSILON);
+        assert!(value.angle_between(Vec3::new(1.0, -1.0, 1.0)) < 0.001);
+    }
+
+    #[test]
+    fn within_limits() {
+        let mut modifier = ClampMagnitude::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.3, 0.0).into()),
+            (0.3, 0.0).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (-2.0).into()),
+            (-1.0).into()
+        );
+        assert_eq!(modifier.apply(&actions, &time, true.into()), true.into());
+    }
+
+    #[test]
+    fn negative_max() {
+        let mut modifier = ClampMagnitude::new(-1.0);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 2.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, 0.0, -2.0).into()),
+            (0.0, 0.0, -1.0).into()
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Clamps each axis to [`Self::max_length`] and then scales the result back
+/// to [`Self::max_length`] if it's longer, preserving the direction.
+///
+/// Useful to cap movement input without making diagonal movement faster.
+///
+/// [`ActionValue::Bool`] is passed through unchanged.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClampThenNormalize {
+    /// Maximum value for each axis and for the length of the result.
+    ///
+    /// By default set to 1.0.
+    pub max_length: f32,
+}
+
+impl ClampThenNormalize {
+    #[must_use]
+    pub fn new(max_length: f32) -> Self {
+        Self { max_length }
+    }
+}
+
+impl Default for ClampThenNormalize {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl InputModifier for ClampThenNormalize {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(_) => value,
+            ActionValue::Axis1D(value) => value.clamp(-self.max_length, self.max_length).into(),
+            ActionValue::Axis2D(value) => value
+                .clamp(Vec2::splat(-self.max_length), Vec2::splat(self.max_length))
+                .clamp_length_max(self.max_length)
+                .into(),
+            ActionValue::Axis3D(value) => value
+                .clamp(Vec3::splat(-self.max_length), Vec3::splat(self.max_length))
+                .clamp_length_max(self.max_length)
+                .into(),
+        }
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal() {
+        let mut modifier = ClampThenNormalize::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        let value = modifier
+            .apply(&actions, &time, (2.0, 2.0).into())
+            .as_axis2d();
+        assert!((value.length() - 1.0).abs() < f32::EPSILON);
+        assert!((value.to_angle() - Vec2::ONE.to_angle()).abs() < f32::EPSILON);
+
+        let value = modifier
+            .apply(&actions, &time, (3.0, -3.0, 3.0).into())
+            .as_axis3d();
+        assert!((value.length() - 1.0).abs() < f32::EPSILON);
+        assert!(value.angle_between(Vec3::new(1.0, -1.0, 1.0)) < 0.001);
+    }
+
+    #[test]
+    fn within_limits() {
+        let mut modifier = ClampThenNormalize::new(2.0);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (1.0, 0.5).into()),
+            (1.0, 0.5).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (-3.0).into()),
+            (-2.0).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, 5.0).into()),
+            (0.0, 2.0).into()
+        );
+        assert_eq!(modifier.apply(&actions, &time, true.into()), true.into());
+    }
+}
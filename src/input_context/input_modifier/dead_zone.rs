@@ -11,6 +11,7 @@ use crate::{action_value::ActionValue, input_context::context_instance::ActionsD
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeadZone {
     /// Defines how axes are processed.
     ///
@@ -100,10 +101,19 @@ impl InputModifier for DeadZone {
             },
         }
     }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
 }
 
 /// Dead zone behavior.
 #[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeadZoneKind {
     /// Apply dead zone logic to all axes simultaneously.
     ///
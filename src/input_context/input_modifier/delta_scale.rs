@@ -3,11 +3,41 @@ use bevy::prelude::*;
 use super::InputModifier;
 use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
 
-/// Multiplies the input value by delta time for this frame.
+/// Multiplies the input value by delta time for this frame and scales it independently along each axis.
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Clone, Copy, Debug)]
-pub struct DeltaScale;
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeltaScale {
+    /// The factor applied to the input value in addition to delta time.
+    ///
+    /// By default set to [`Vec3::ONE`], which only multiplies by delta time.
+    pub factor: Vec3,
+}
+
+impl DeltaScale {
+    /// Creates a new instance with all axes set to `value`.
+    #[must_use]
+    pub fn splat(value: f32) -> Self {
+        Self::new(Vec3::splat(value))
+    }
+
+    /// Creates a new instance with a separate factor for each axis.
+    ///
+    /// Each component of the value is multiplied by delta time and the matching component of `factor`.
+    /// Lower dimensions use only the leading components, for example, [`ActionValue::Axis2D`] uses X and Y.
+    #[must_use]
+    pub fn new(factor: Vec3) -> Self {
+        Self { factor }
+    }
+}
+
+/// Sets [`DeltaScale::factor`] to [`Vec3::ONE`], so the value is only multiplied by delta time.
+impl Default for DeltaScale {
+    fn default() -> Self {
+        Self::splat(1.0)
+    }
+}
 
 impl InputModifier for DeltaScale {
     fn apply(
@@ -16,16 +46,25 @@ impl InputModifier for DeltaScale {
         time: &Time<Virtual>,
         value: ActionValue,
     ) -> ActionValue {
+        let factor = self.factor * time.delta_secs();
         match value {
             ActionValue::Bool(value) => {
                 let value = if value { 1.0 } else { 0.0 };
-                (value * time.delta_secs()).into()
+                (value * factor.x).into()
             }
-            ActionValue::Axis1D(value) => (value * time.delta_secs()).into(),
-            ActionValue::Axis2D(value) => (value * time.delta_secs()).into(),
-            ActionValue::Axis3D(value) => (value * time.delta_secs()).into(),
+            ActionValue::Axis1D(value) => (value * factor.x).into(),
+            ActionValue::Axis2D(value) => (value * factor.xy()).into(),
+            ActionValue::Axis3D(value) => (value * factor).into(),
         }
     }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -36,20 +75,40 @@ mod tests {
 
     #[test]
     fn scaling() {
+        let mut modifier = DeltaScale::default();
         let actions = ActionsData::default();
         let mut time = Time::default();
         time.advance_by(Duration::from_millis(500));
 
-        assert_eq!(DeltaScale.apply(&actions, &time, true.into()), 0.5.into());
-        assert_eq!(DeltaScale.apply(&actions, &time, false.into()), 0.0.into());
-        assert_eq!(DeltaScale.apply(&actions, &time, 0.5.into()), 0.25.into());
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 0.5.into());
+        assert_eq!(modifier.apply(&actions, &time, false.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 0.25.into());
         assert_eq!(
-            DeltaScale.apply(&actions, &time, Vec2::ONE.into()),
+            modifier.apply(&actions, &time, Vec2::ONE.into()),
             (0.5, 0.5).into()
         );
         assert_eq!(
-            DeltaScale.apply(&actions, &time, Vec3::ONE.into()),
+            modifier.apply(&actions, &time, Vec3::ONE.into()),
             (0.5, 0.5, 0.5).into()
         );
     }
+
+    #[test]
+    fn per_axis() {
+        let mut modifier = DeltaScale::new((2.0, 4.0, 1.0).into());
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(500));
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec2::ONE.into()),
+            (1.0, 2.0).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec3::ONE.into()),
+            (1.0, 2.0, 0.5).into()
+        );
+    }
 }
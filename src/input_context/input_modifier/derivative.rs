@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Outputs the rate of change of the input per second.
+///
+/// Calculated per axis as the difference from the previous value divided by delta time.
+/// Useful for detecting flicks of a stick or mouse.
+///
+/// Outputs zero on the first evaluation and when delta time is zero.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Derivative {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    previous: Option<Vec3>,
+}
+
+impl Derivative {
+    /// Forgets the previous value, so the next evaluation outputs zero.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl InputModifier for Derivative {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.apply(_actions, time, value.into());
+        }
+
+        let current = value.as_axis3d();
+        let previous = self.previous.replace(current);
+        let delta = time.delta_secs();
+        let rate = match previous {
+            Some(previous) if delta > 0.0 => (current - previous) / delta,
+            _ => Vec3::ZERO,
+        };
+
+        ActionValue::Axis3D(rate).convert(value.dim())
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn ramp() {
+        let mut modifier = Derivative::default();
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        assert_eq!(modifier.apply(&actions, &time, 0.0.into()), 0.0.into());
+        for step in 1..5 {
+            let value = modifier
+                .apply(&actions, &time, (step as f32 * 0.2).into())
+                .as_axis1d();
+            assert!((value - 2.0).abs() < 1e-4, "`{value}` should be constant");
+        }
+    }
+
+    #[test]
+    fn constant() {
+        let mut modifier = Derivative::default();
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        for _ in 0..3 {
+            assert_eq!(
+                modifier.apply(&actions, &time, (0.5, -1.0).into()),
+                Vec2::ZERO.into()
+            );
+        }
+    }
+
+    #[test]
+    fn zero_delta() {
+        let mut modifier = Derivative::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 0.0.into());
+    }
+
+    #[test]
+    fn reset() {
+        let mut modifier = Derivative::default();
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        modifier.apply(&actions, &time, 0.0.into());
+        modifier.reset();
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 0.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, false.into()),
+            (-10.0).into()
+        );
+    }
+}
@@ -9,6 +9,7 @@ use crate::{action_value::ActionValue, input_context::context_instance::ActionsD
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExponentialCurve {
     /// Curve exponent.
     pub exp: Vec3,
@@ -53,6 +54,15 @@ impl InputModifier for ExponentialCurve {
             }
         }
     }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        // Zero to the power of zero is one.
+        self.exp.cmpgt(Vec3::ZERO).all()
+    }
 }
 
 fn apply_exp(value: f32, exp: f32) -> f32 {
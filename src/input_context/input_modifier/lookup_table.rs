@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Maps the input magnitude through a sampled response curve.
+///
+/// Samples are evenly spaced over the input magnitude from 0.0 to 1.0 and linearly
+/// interpolated between. Magnitudes above 1.0 use the last sample. The direction of
+/// the input is preserved.
+///
+/// Useful for artist-driven response curves. Since modifiers don't have access to assets,
+/// the samples need to be copied from the loaded asset during
+/// [`InputContext::context_instance`](crate::input_context::InputContext::context_instance).
+/// Trigger [`RebuildInputContexts`](crate::input_context::RebuildInputContexts)
+/// to pick up a modified asset.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<f32>", into = "Vec<f32>"))]
+pub struct LookupTable {
+    samples: Vec<f32>,
+}
+
+impl LookupTable {
+    /// Creates a new instance from the curve samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than 2 samples.
+    #[must_use]
+    pub fn new(samples: impl Into<Vec<f32>>) -> Self {
+        Self::try_from(samples.into()).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns the curve samples.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    fn sample(&self, magnitude: f32) -> f32 {
+        let last_index = self.samples.len() - 1;
+        let position = magnitude.clamp(0.0, 1.0) * last_index as f32;
+        let index = (position as usize).min(last_index - 1);
+        let t = position - index as f32;
+
+        let start = self.samples[index];
+        let end = self.samples[index + 1];
+        start + (end - start) * t
+    }
+}
+
+impl TryFrom<Vec<f32>> for LookupTable {
+    type Error = &'static str;
+
+    fn try_from(samples: Vec<f32>) -> Result<Self, Self::Error> {
+        if samples.len() < 2 {
+            return Err("lookup table should contain at least 2 samples");
+        }
+
+        Ok(Self { samples })
+    }
+}
+
+impl From<LookupTable> for Vec<f32> {
+    fn from(value: LookupTable) -> Self {
+        value.samples
+    }
+}
+
+impl InputModifier for LookupTable {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        let dim = value.dim();
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.sample(value).into();
+        }
+
+        let value = value.as_axis3d();
+        let magnitude = value.length();
+        if magnitude == 0.0 {
+            return ActionValue::zero(dim);
+        }
+
+        let mapped = value * self.sample(magnitude) / magnitude;
+        ActionValue::Axis3D(mapped).convert(dim)
+    }
+
+    fn preserves_zero(&self) -> bool {
+        // Evaluated on every call since it depends on the samples.
+        self.samples[0] == 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapping() {
+        let mut modifier = LookupTable::new([0.0, 0.1, 1.0]);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.25.into()), 0.05.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 0.1.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.75.into()), 0.55.into());
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, 2.0.into()),
+            1.0.into(),
+            "should clamp to the last sample"
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (-0.5).into()),
+            (-0.1).into(),
+            "should preserve direction"
+        );
+    }
+
+    #[test]
+    fn dimensions() {
+        let mut modifier = LookupTable::new([0.0, 0.5]);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 0.5.into());
+        assert_eq!(modifier.apply(&actions, &time, false.into()), 0.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec2::Y.into()),
+            (0.0, 0.5).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec3::ZERO.into()),
+            Vec3::ZERO.into()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_sample() {
+        let _ = LookupTable::new([1.0]);
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Integrates the input into a velocity to produce slippery movement.
+///
+/// While the input is held, the velocity accelerates in its direction.
+/// Friction constantly slows the velocity down, so it approaches
+/// `acceleration / friction` while held and decays to zero after release.
+/// Changing the direction smoothly reverses the velocity instead of snapping.
+///
+/// The integration is exact for any delta time,
+/// so the velocity follows the same trajectory at any frame rate.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Momentum {
+    /// Velocity change per second for the input with length 1.0.
+    pub acceleration: f32,
+
+    /// Exponential decay rate of the velocity per second.
+    ///
+    /// If set to 0.0, the velocity will never decay.
+    pub friction: f32,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    velocity: Vec3,
+}
+
+impl Momentum {
+    #[must_use]
+    pub fn new(acceleration: f32, friction: f32) -> Self {
+        Self {
+            acceleration,
+            friction,
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Stops the accumulated velocity immediately.
+    pub fn reset(&mut self) {
+        self.velocity = Vec3::ZERO;
+    }
+}
+
+impl InputModifier for Momentum {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.apply(_actions, time, value.into());
+        }
+
+        let input = value.as_axis3d();
+        let delta = time.delta_secs();
+        if self.friction > 0.0 {
+            let max_velocity = input * self.acceleration / self.friction;
+            self.velocity =
+                max_velocity + (self.velocity - max_velocity) * (-self.friction * delta).exp();
+        } else {
+            self.velocity += input * self.acceleration * delta;
+        }
+
+        ActionValue::Axis3D(self.velocity).convert(value.dim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn hold() {
+        let mut modifier = Momentum::new(10.0, 5.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        let mut previous = 0.0;
+        for _ in 0..20 {
+            let value = modifier.apply(&actions, &time, true.into()).as_axis1d();
+            assert!(value > previous, "should accelerate while held");
+            assert!(value < 2.0, "shouldn't exceed the max speed");
+            previous = value;
+        }
+        assert!(previous > 1.9, "should approach the max speed");
+    }
+
+    #[test]
+    fn release() {
+        let mut modifier = Momentum::new(10.0, 5.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        for _ in 0..10 {
+            modifier.apply(&actions, &time, true.into());
+        }
+
+        let mut previous = modifier.apply(&actions, &time, false.into()).as_axis1d();
+        for _ in 0..20 {
+            let value = modifier.apply(&actions, &time, false.into()).as_axis1d();
+            assert!(value < previous, "should decay after release");
+            assert!(value > 0.0, "shouldn't overshoot");
+            previous = value;
+        }
+        assert!(previous < 0.01, "should approach zero");
+    }
+
+    #[test]
+    fn reverse() {
+        let mut modifier = Momentum::new(10.0, 5.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        for _ in 0..10 {
+            modifier.apply(&actions, &time, Vec2::X.into());
+        }
+
+        let value = modifier.apply(&actions, &time, Vec2::NEG_X.into());
+        assert!(
+            value.as_axis2d().x > 0.0,
+            "should keep moving forward right after reversing"
+        );
+
+        let mut previous = value.as_axis2d().x;
+        for _ in 0..20 {
+            let value = modifier
+                .apply(&actions, &time, Vec2::NEG_X.into())
+                .as_axis2d()
+                .x;
+            assert!(value < previous, "should smoothly reverse");
+            previous = value;
+        }
+        assert!(previous < -1.9);
+    }
+
+    #[test]
+    fn reset() {
+        let mut modifier = Momentum::new(10.0, 5.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        modifier.apply(&actions, &time, true.into());
+        modifier.reset();
+
+        assert_eq!(modifier.apply(&actions, &time, false.into()), 0.0.into());
+    }
+
+    #[test]
+    fn no_friction() {
+        let mut modifier = Momentum::new(10.0, 0.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(modifier.apply(&actions, &time, false.into()), 1.0.into());
+    }
+}
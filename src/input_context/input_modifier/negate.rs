@@ -9,6 +9,7 @@ use crate::{action_value::ActionValue, input_context::context_instance::ActionsD
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Negate {
     /// Whether to inverse the X axis.
     pub x: bool,
@@ -111,6 +112,15 @@ impl InputModifier for Negate {
             }
         }
     }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        let mut modifier = *self;
+        modifier.apply(&ActionsData::default(), &Time::default(), axes)
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
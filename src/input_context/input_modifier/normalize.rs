@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Scales multidimensional input to unit length.
+///
+/// Inputs with a length not greater than [`Self::epsilon`] become zero
+/// to avoid amplifying noise into a random direction.
+///
+/// Only affects [`ActionValue::Axis2D`] and [`ActionValue::Axis3D`].
+/// Other values are passed through unchanged.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Normalize {
+    /// Length at or below which input is treated as zero.
+    ///
+    /// By default set to 0.001.
+    pub epsilon: f32,
+}
+
+impl Normalize {
+    #[must_use]
+    pub fn new(epsilon: f32) -> Self {
+        Self { epsilon }
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self::new(0.001)
+    }
+}
+
+impl InputModifier for Normalize {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(_) | ActionValue::Axis1D(_) => value,
+            ActionValue::Axis2D(value) => {
+                let length = value.length();
+                if length > self.epsilon {
+                    (value / length).into()
+                } else {
+                    Vec2::ZERO.into()
+                }
+            }
+            ActionValue::Axis3D(value) => {
+                let length = value.length();
+                if length > self.epsilon {
+                    (value / length).into()
+                } else {
+                    Vec3::ZERO.into()
+                }
+            }
+        }
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalization() {
+        let mut modifier = Normalize::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (3.0, 4.0).into()),
+            (0.6, 0.8).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, 0.0, -2.0).into()),
+            (0.0, 0.0, -1.0).into()
+        );
+    }
+
+    #[test]
+    fn epsilon() {
+        let mut modifier = Normalize::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0001, 0.0).into()),
+            Vec2::ZERO.into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec3::ZERO.into()),
+            Vec3::ZERO.into()
+        );
+    }
+
+    #[test]
+    fn passthrough() {
+        let mut modifier = Normalize::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), true.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 0.5.into());
+    }
+}
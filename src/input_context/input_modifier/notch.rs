@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Accumulates input and produces a discrete step each time it crosses [`Self::size`].
+///
+/// Useful for devices that report fractional mouse wheel deltas when you need
+/// exactly one step per notch, like switching inventory slots.
+/// The remainder is kept for the next frames.
+///
+/// For multi-dimensional values only the Y axis is used, since it's the vertical axis of the mouse wheel.
+/// The output is always [`ActionValue::Axis1D`] with the number of steps, signed by the scroll direction.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Notch {
+    /// Amount of input needed for a single step.
+    ///
+    /// By default set to 1.0, which matches a single line of [`MouseScrollUnit::Line`](bevy::input::mouse::MouseScrollUnit::Line).
+    /// Non-positive values produce no steps.
+    pub size: f32,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    accumulated: f32,
+}
+
+impl Notch {
+    /// Creates a new instance with the specified step size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not positive.
+    #[must_use]
+    pub fn new(size: f32) -> Self {
+        assert!(size > 0.0, "notch size should be positive, got `{size}`");
+        Self {
+            size,
+            accumulated: 0.0,
+        }
+    }
+}
+
+impl Default for Notch {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl InputModifier for Notch {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        let value = match value {
+            ActionValue::Bool(_) | ActionValue::Axis1D(_) => value.as_axis1d(),
+            ActionValue::Axis2D(value) => value.y,
+            ActionValue::Axis3D(value) => value.y,
+        };
+
+        if self.size <= 0.0 {
+            return 0.0.into();
+        }
+
+        self.accumulated += value;
+        let steps = (self.accumulated / self.size).trunc();
+        self.accumulated -= steps * self.size;
+
+        steps.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractional() {
+        let mut modifier = Notch::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.4.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.4.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.4.into()), 1.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.8.into()), 1.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.0.into()), 0.0.into());
+    }
+
+    #[test]
+    fn negative() {
+        let mut modifier = Notch::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, (-0.6).into()), 0.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, (-0.6).into()),
+            (-1.0).into()
+        );
+        assert_eq!(modifier.apply(&actions, &time, 0.9.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.2.into()), 0.0.into());
+    }
+
+    #[test]
+    fn multiple_steps() {
+        let mut modifier = Notch::new(0.5);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 1.2.into()), 2.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.3.into()), 1.0.into());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        let _ = Notch::new(0.0);
+    }
+
+    #[test]
+    fn invalid_size() {
+        let mut modifier = Notch {
+            size: 0.0,
+            accumulated: 0.0,
+        };
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 0.0.into());
+
+        modifier.size = 1.0;
+        assert_eq!(
+            modifier.apply(&actions, &time, 1.0.into()),
+            1.0.into(),
+            "accumulated value shouldn't be corrupted by the invalid size"
+        );
+    }
+
+    #[test]
+    fn wheel_y() {
+        let mut modifier = Notch::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (5.0, 0.5).into()),
+            0.0.into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, 0.5).into()),
+            1.0.into()
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Applies a dead zone to the magnitude of multidimensional input as a whole.
+///
+/// Inputs with a length below [`Self::lower_threshold`] become zero, inputs above
+/// [`Self::upper_threshold`] are clamped to unit length and the length in between
+/// is linearly rescaled onto the range 0 to 1. The direction is always preserved,
+/// so diagonal stick input doesn't snap toward the axes.
+///
+/// Unlike [`DeadZone`](super::dead_zone::DeadZone), only affects [`ActionValue::Axis2D`]
+/// and [`ActionValue::Axis3D`]. Other values are passed through unchanged.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadialDeadZone {
+    /// Length below which input is ignored.
+    ///
+    /// By default set to 0.2.
+    pub lower_threshold: f32,
+
+    /// Length above which input is clamped to 1.
+    ///
+    /// By default set to 1.0.
+    pub upper_threshold: f32,
+}
+
+impl RadialDeadZone {
+    #[must_use]
+    pub fn with_lower_threshold(mut self, lower_threshold: f32) -> Self {
+        self.lower_threshold = lower_threshold;
+        self
+    }
+
+    #[must_use]
+    pub fn with_upper_threshold(mut self, upper_threshold: f32) -> Self {
+        self.upper_threshold = upper_threshold;
+        self
+    }
+
+    fn scale(self, length: f32) -> f32 {
+        let lower_bound = (length - self.lower_threshold).max(0.0);
+        let scaled_length = lower_bound / (self.upper_threshold - self.lower_threshold);
+        scaled_length.min(1.0)
+    }
+}
+
+impl Default for RadialDeadZone {
+    fn default() -> Self {
+        Self {
+            lower_threshold: 0.2,
+            upper_threshold: 1.0,
+        }
+    }
+}
+
+impl InputModifier for RadialDeadZone {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(_) | ActionValue::Axis1D(_) => value,
+            ActionValue::Axis2D(value) => {
+                (value.normalize_or_zero() * self.scale(value.length())).into()
+            }
+            ActionValue::Axis3D(value) => {
+                (value.normalize_or_zero() * self.scale(value.length())).into()
+            }
+        }
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_context::input_modifier::dead_zone::{DeadZone, DeadZoneKind};
+
+    #[test]
+    fn rescaling() {
+        let mut modifier = RadialDeadZone::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.1, 0.1).into()),
+            Vec2::ZERO.into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, 0.5).into()),
+            (0.0, 0.375).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (2.0, 0.0).into()),
+            (1.0, 0.0).into(),
+            "should clamp to unit length"
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.0, 0.0, -0.5).into()),
+            (0.0, 0.0, -0.375).into()
+        );
+    }
+
+    #[test]
+    fn direction() {
+        let mut modifier = RadialDeadZone::default();
+        let mut axial = DeadZone::new(DeadZoneKind::Axial);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        // Just above the threshold, but each axis is below it.
+        let value = Vec2::new(0.2, 0.1);
+        let radial_value = modifier.apply(&actions, &time, value.into()).as_axis2d();
+        let axial_value = axial.apply(&actions, &time, value.into()).as_axis2d();
+
+        assert!(radial_value.length() > 0.0);
+        assert!(
+            radial_value
+                .normalize()
+                .abs_diff_eq(value.normalize(), 1e-6),
+            "should preserve direction"
+        );
+        assert_eq!(
+            axial_value,
+            Vec2::ZERO,
+            "per-axis dead zone should discard the same input"
+        );
+    }
+
+    #[test]
+    fn passthrough() {
+        let mut modifier = RadialDeadZone::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), true.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.1.into()), 0.1.into());
+        assert_eq!(modifier.apply(&actions, &time, 2.0.into()), 2.0.into());
+    }
+}
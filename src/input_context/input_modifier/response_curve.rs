@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Maps input values through a piecewise linear response curve, per axis.
+///
+/// Each control point maps an input magnitude (`x`) to an output magnitude (`y`).
+/// Values between points are linearly interpolated and values outside the defined
+/// domain are clamped to the endpoints. The sign of each axis is preserved.
+///
+/// Points need to be sorted by `x` and there should be at least 2 of them.
+/// Otherwise the modifier logs a warning and passes values through unchanged.
+///
+/// Useful for fine-tuning throttles and brakes when [`ExponentialCurve`](super::exponential_curve::ExponentialCurve)
+/// is not flexible enough.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// # let mut ctx = ContextInstance::default();
+/// // Precise control near the rest position and a quick ramp-up after the half.
+/// ctx.bind::<Throttle>()
+///     .to(GamepadButton::RightTrigger2)
+///     .with_modifiers(ResponseCurve::new([
+///         Vec2::new(0.0, 0.0),
+///         Vec2::new(0.5, 0.2),
+///         Vec2::new(1.0, 1.0),
+///     ]));
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = f32)]
+/// # struct Throttle;
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResponseCurve {
+    /// Control points sorted by input magnitude.
+    pub points: Vec<Vec2>,
+}
+
+impl ResponseCurve {
+    #[must_use]
+    pub fn new(points: impl Into<Vec<Vec2>>) -> Self {
+        Self {
+            points: points.into(),
+        }
+    }
+
+    /// Returns `true` if there are at least 2 points sorted by `x`.
+    pub fn is_valid(&self) -> bool {
+        self.points.len() >= 2
+            && self
+                .points
+                .windows(2)
+                .all(|window| window[0].x <= window[1].x)
+    }
+
+    /// Maps a single axis value through the curve.
+    ///
+    /// Assumes that the curve is valid.
+    fn sample(&self, value: f32) -> f32 {
+        let magnitude = value.abs();
+        let first = self.points[0];
+        let last = self.points[self.points.len() - 1];
+
+        let mapped = if magnitude <= first.x {
+            first.y
+        } else if magnitude >= last.x {
+            last.y
+        } else {
+            let index = self
+                .points
+                .partition_point(|point| point.x <= magnitude)
+                .max(1);
+            let start = self.points[index - 1];
+            let end = self.points[index];
+            let t = (magnitude - start.x) / (end.x - start.x);
+            start.y + (end.y - start.y) * t
+        };
+
+        mapped.copysign(value)
+    }
+}
+
+impl InputModifier for ResponseCurve {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        if !self.is_valid() {
+            warn_once!(
+                "response curve should contain at least 2 points sorted by `x`, but got `{:?}`",
+                self.points
+            );
+            return value;
+        }
+
+        match value {
+            ActionValue::Bool(value) => {
+                let value = if value { 1.0 } else { 0.0 };
+                self.sample(value).into()
+            }
+            ActionValue::Axis1D(value) => self.sample(value).into(),
+            ActionValue::Axis2D(value) => value.map(|value| self.sample(value)).into(),
+            ActionValue::Axis3D(value) => value.map(|value| self.sample(value)).into(),
+        }
+    }
+
+    fn preserves_zero(&self) -> bool {
+        // Evaluated on every call since `points` can be modified at any time.
+        !self.is_valid() || self.sample(0.0) == 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolation() {
+        let mut modifier = ResponseCurve::new([
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.5, 0.25),
+            Vec2::new(1.0, 1.0),
+        ]);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.25.into()), 0.125.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 0.25.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.75.into()), 0.625.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, (-0.25).into()),
+            (-0.125).into(),
+            "should preserve sign"
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.25, -0.75).into()),
+            (0.125, -0.625).into()
+        );
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 1.0.into());
+    }
+
+    #[test]
+    fn clamping() {
+        let mut modifier = ResponseCurve::new([Vec2::new(0.2, 0.1), Vec2::new(0.8, 0.9)]);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.1.into()), 0.1.into());
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 0.9.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, (-2.0).into()),
+            (-0.9).into()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        let mut modifier = ResponseCurve::new([Vec2::new(0.5, 1.0)]);
+        assert!(!modifier.is_valid());
+        assert_eq!(
+            modifier.apply(&actions, &time, 0.3.into()),
+            0.3.into(),
+            "should pass through with a single point"
+        );
+
+        let mut modifier = ResponseCurve::new([Vec2::new(1.0, 1.0), Vec2::new(0.0, 0.0)]);
+        assert!(!modifier.is_valid());
+        assert_eq!(
+            modifier.apply(&actions, &time, 0.3.into()),
+            0.3.into(),
+            "should pass through with unsorted points"
+        );
+    }
+}
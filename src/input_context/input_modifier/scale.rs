@@ -7,6 +7,7 @@ use crate::{action_value::ActionValue, input_context::context_instance::ActionsD
 ///
 /// [`ActionValue::Bool`] will be converted into [`ActionValue::Axis1D`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scale {
     /// The factor applied to the input value.
     ///
@@ -44,6 +45,15 @@ impl InputModifier for Scale {
             ActionValue::Axis3D(value) => (value * self.factor).into(),
         }
     }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        let mut modifier = *self;
+        modifier.apply(&ActionsData::default(), &Time::default(), axes)
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -68,4 +78,22 @@ mod tests {
             (2.0, 2.0, 2.0).into()
         );
     }
+
+    #[test]
+    fn per_axis() {
+        let mut modifier = Scale::new((2.0, 3.0, 4.0).into());
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 2.0.into());
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 1.0.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec2::ONE.into()),
+            (2.0, 3.0).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec3::ONE.into()),
+            (2.0, 3.0, 4.0).into()
+        );
+    }
 }
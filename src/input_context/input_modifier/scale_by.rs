@@ -0,0 +1,130 @@
+use std::{any, marker::PhantomData};
+
+use bevy::prelude::*;
+
+use super::{scale::Scale, InputModifier};
+use crate::{
+    action_value::ActionValue,
+    input_context::context_instance::{ActionState, ActionsData},
+    InputAction,
+};
+
+/// Scales input by a factor while another action is fired within the same context.
+///
+/// When action `A` is [`ActionState::Fired`], behaves like [`Scale`].
+/// Otherwise, passes the value unchanged.
+///
+/// Action `A` needs to be bound before the action with this modifier,
+/// otherwise its state from the previous frame will be used.
+///
+/// [`ActionValue::Bool`] will be converted into [`ActionValue::Axis1D`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct ScaleBy<A: InputAction> {
+    /// The factor applied to the input value while `A` is fired.
+    pub factor: Vec3,
+
+    /// Action that enables scaling.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    marker: PhantomData<A>,
+}
+
+impl<A: InputAction> ScaleBy<A> {
+    /// Creates a new instance with all axes set to `value`.
+    #[must_use]
+    pub fn splat(value: f32) -> Self {
+        Self::new(Vec3::splat(value))
+    }
+
+    #[must_use]
+    pub fn new(factor: Vec3) -> Self {
+        Self {
+            factor,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: InputAction> InputModifier for ScaleBy<A> {
+    fn apply(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        let factor = match actions.action::<A>() {
+            Some(action) if action.state() == ActionState::Fired => self.factor,
+            Some(_) => Vec3::ONE,
+            None => {
+                warn_once!(
+                    "action `{}` is not present in context",
+                    any::type_name::<A>()
+                );
+                Vec3::ONE
+            }
+        };
+
+        Scale::new(factor).apply(actions, time, value)
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_enhanced_input_macros::InputAction;
+
+    use super::*;
+    use crate::input_context::context_instance::ActionData;
+
+    #[test]
+    fn active() {
+        let mut modifier = ScaleBy::<DummyAction>::splat(2.0);
+        let mut action = ActionData::new::<DummyAction>();
+        let time = Time::default();
+        action.update(&time, ActionState::Fired, true);
+        let mut actions = ActionsData::default();
+        actions.insert_action::<DummyAction>(action);
+
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec2::ONE.into()),
+            Vec2::splat(2.0).into()
+        );
+        assert_eq!(modifier.apply(&actions, &time, true.into()), 2.0.into());
+    }
+
+    #[test]
+    fn inactive() {
+        let mut modifier = ScaleBy::<DummyAction>::splat(2.0);
+        let mut action = ActionData::new::<DummyAction>();
+        let time = Time::default();
+        action.update(&time, ActionState::Ongoing, true);
+        let mut actions = ActionsData::default();
+        actions.insert_action::<DummyAction>(action);
+
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec2::ONE.into()),
+            Vec2::ONE.into()
+        );
+    }
+
+    #[test]
+    fn missing_action() {
+        let mut modifier = ScaleBy::<DummyAction>::splat(2.0);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 1.0.into()), 1.0.into());
+    }
+
+    #[derive(Debug, InputAction)]
+    #[input_action(output = bool)]
+    struct DummyAction;
+}
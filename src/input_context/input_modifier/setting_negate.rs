@@ -0,0 +1,99 @@
+use std::{
+    any,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+use bevy::prelude::*;
+
+use super::{negate::Negate, InputModifier};
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Applies [`Negate`] only while the boolean setting `R` is enabled.
+///
+/// Useful for user preferences like "invert look Y".
+/// The setting should be registered using
+/// [`ContextAppExt::add_input_setting`](crate::input_context::ContextAppExt::add_input_setting).
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_input_setting::<InvertLookY>();
+///
+/// #[derive(Resource, Deref)]
+/// struct InvertLookY(bool);
+///
+/// # let mut ctx = ContextInstance::default();
+/// ctx.bind::<Look>()
+///     .to(Input::mouse_motion())
+///     .with_modifiers(SettingNegate::<InvertLookY>::new(Negate::y()));
+/// # #[derive(Debug, InputAction)]
+/// # #[input_action(output = Vec2)]
+/// # struct Look;
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct SettingNegate<R: Resource> {
+    /// Axes to invert while the setting is enabled.
+    pub negate: Negate,
+
+    /// Required setting.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    marker: PhantomData<R>,
+}
+
+impl<R: Resource> SettingNegate<R> {
+    #[must_use]
+    pub fn new(negate: Negate) -> Self {
+        Self {
+            negate,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<R: Resource> Debug for SettingNegate<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SettingNegate")
+            .field("setting", &any::type_name::<R>())
+            .field("negate", &self.negate)
+            .finish()
+    }
+}
+
+impl<R: Resource> Clone for SettingNegate<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: Resource> Copy for SettingNegate<R> {}
+
+impl<R: Resource> InputModifier for SettingNegate<R> {
+    fn apply(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        match actions.setting::<R>() {
+            Some(true) => self.negate.apply(actions, time, value),
+            Some(false) => value,
+            None => {
+                warn_once!(
+                    "setting `{}` is not registered as input setting",
+                    any::type_name::<R>()
+                );
+                value
+            }
+        }
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
+}
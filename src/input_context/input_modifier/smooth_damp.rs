@@ -0,0 +1,175 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Gradually moves the value towards the input using a critically damped spring.
+///
+/// Unlike [`SmoothNudge`](super::smooth_nudge::SmoothNudge), tracks the velocity,
+/// so the value eases in and out without overshooting the input.
+/// Mirrors the behavior of `SmoothDamp` from Unity.
+///
+/// Uses [`Time::delta_secs`], so the smoothing follows [`Time<Virtual>`] speed
+/// like other time-based modifiers and stops while it's paused.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothDamp {
+    /// Approximate time in seconds to reach the input.
+    pub smooth_time: f32,
+
+    /// Maximum speed of the value change per second.
+    ///
+    /// By default set to [`f32::INFINITY`].
+    pub max_speed: f32,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    current_value: Vec3,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    velocity: Vec3,
+}
+
+impl SmoothDamp {
+    #[must_use]
+    pub fn new(smooth_time: f32) -> Self {
+        Self {
+            smooth_time,
+            max_speed: f32::INFINITY,
+            current_value: Vec3::ZERO,
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    #[must_use]
+    pub fn with_max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = max_speed;
+        self
+    }
+}
+
+impl Default for SmoothDamp {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl InputModifier for SmoothDamp {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.apply(_actions, time, value.into());
+        }
+
+        let delta = time.delta_secs();
+        if delta > 0.0 {
+            let target_value = value.as_axis3d();
+            let smooth_time = self.smooth_time.max(1e-4);
+            let omega = 2.0 / smooth_time;
+
+            // Approximation of `exp(-omega * delta)`.
+            let x = omega * delta;
+            let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+            let change =
+                (self.current_value - target_value).clamp_length_max(self.max_speed * smooth_time);
+            let clamped_target = self.current_value - change;
+
+            let temp = (self.velocity + omega * change) * delta;
+            self.velocity = (self.velocity - omega * temp) * decay;
+            let mut new_value = clamped_target + (change + temp) * decay;
+
+            // Prevent overshooting.
+            if (target_value - self.current_value).dot(new_value - target_value) > 0.0 {
+                new_value = target_value;
+                self.velocity = Vec3::ZERO;
+            }
+
+            self.current_value = new_value;
+        }
+
+        ActionValue::Axis3D(self.current_value).convert(value.dim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn convergence() {
+        let mut modifier = SmoothDamp::new(0.25);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        let mut last_value = 0.0;
+        for _ in 0..120 {
+            time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            let value = modifier.apply(&actions, &time, 1.0.into()).as_axis1d();
+            assert!(
+                value >= last_value,
+                "should approach the target monotonically"
+            );
+            assert!(value <= 1.0, "`{value}` shouldn't overshoot");
+            last_value = value;
+        }
+
+        assert!(
+            (last_value - 1.0).abs() < 1e-3,
+            "`{last_value}` should converge to the target"
+        );
+    }
+
+    #[test]
+    fn axis2d() {
+        let mut modifier = SmoothDamp::new(0.25);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+        time.advance_by(Duration::from_millis(100));
+
+        let value = modifier
+            .apply(&actions, &time, (1.0, -1.0).into())
+            .as_axis2d();
+        assert!(value.x > 0.0 && value.x < 1.0);
+        assert_eq!(value.x, -value.y, "should preserve direction");
+    }
+
+    #[test]
+    fn max_speed() {
+        let mut modifier = SmoothDamp::new(0.25).with_max_speed(1.0);
+        let actions = ActionsData::default();
+        let mut time = Time::default();
+
+        for _ in 0..10 {
+            time.advance_by(Duration::from_millis(10));
+            modifier.apply(&actions, &time, 100.0.into());
+        }
+
+        let value = modifier.apply(&actions, &time, 100.0.into()).as_axis1d();
+        assert!(value <= 0.11, "`{value}` should be limited by the speed");
+    }
+
+    #[test]
+    fn paused() {
+        let mut modifier = SmoothDamp::new(0.25);
+        let actions = ActionsData::default();
+        let mut time = Time::<Virtual>::default();
+        time.advance_by(Duration::from_millis(100));
+        let value = modifier.apply(&actions, &time, true.into());
+
+        time.pause();
+        time.advance_by(Duration::ZERO);
+        assert_eq!(
+            modifier.apply(&actions, &time, true.into()),
+            value,
+            "value shouldn't change without time passing"
+        );
+    }
+}
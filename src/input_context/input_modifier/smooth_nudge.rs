@@ -6,15 +6,19 @@ use crate::{action_value::ActionValue, input_context::context_instance::ActionsD
 /// Produces a smoothed value of the current and previous input value.
 ///
 /// See [`StableInterpolate::smooth_nudge`] for details.
+/// The interpolation is exponential decay based on delta time,
+/// so the smoothing produces the same trajectory at any frame rate.
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SmoothNudge {
     /// Multiplier for delta time, determines the rate of smoothing.
     ///
     /// By default set to 8.0, an ad-hoc value that usually produces nice results.
     pub decay_rate: f32,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     current_value: Vec3,
 }
 
@@ -97,6 +101,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn frame_rate_independence() {
+        let actions = ActionsData::default();
+
+        let [low_fps, high_fps] = [30.0, 120.0].map(|fps| {
+            let mut modifier = SmoothNudge::default();
+            let mut time = Time::default();
+            let mut value = ActionValue::Axis1D(0.0);
+            for _ in 0..(fps / 2.0) as usize {
+                time.advance_by(Duration::from_secs_f64(1.0 / fps));
+                value = modifier.apply(&actions, &time, 1.0.into());
+            }
+            value.as_axis1d()
+        });
+
+        assert!(low_fps < 1.0, "value shouldn't snap to the target");
+        assert!(
+            (low_fps - high_fps).abs() < 1e-4,
+            "`{low_fps}` at 30 FPS should match `{high_fps}` at 120 FPS"
+        );
+    }
+
     #[test]
     fn snapping() {
         let mut modifier = SmoothNudge::default();
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Suppresses micro-jitter by holding the last stable value.
+///
+/// If the input differs from the last stable value by less than [`Self::threshold`],
+/// the stable value is returned instead. Otherwise the input becomes the new stable value.
+/// Useful for trackpads and noisy sticks that produce tiny changes each frame.
+///
+/// [`ActionValue::Bool`] is passed through unchanged.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stabilize {
+    /// Minimal distance from the last stable value to update it.
+    ///
+    /// By default set to 0.01.
+    pub threshold: f32,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    stable_value: Vec3,
+}
+
+impl Stabilize {
+    #[must_use]
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            stable_value: Default::default(),
+        }
+    }
+}
+
+impl Default for Stabilize {
+    fn default() -> Self {
+        Self::new(0.01)
+    }
+}
+
+impl InputModifier for Stabilize {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(_) = value {
+            return value;
+        }
+
+        let new_value = value.as_axis3d();
+        if self.stable_value.distance(new_value) >= self.threshold {
+            self.stable_value = new_value;
+        }
+
+        ActionValue::Axis3D(self.stable_value).convert(value.dim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jitter() {
+        let mut modifier = Stabilize::new(0.1);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, 0.5.into()), 0.5.into());
+        for value in [0.55, 0.45, 0.52, 0.48] {
+            assert_eq!(
+                modifier.apply(&actions, &time, value.into()),
+                0.5.into(),
+                "`{value}` should be suppressed"
+            );
+        }
+        assert_eq!(modifier.apply(&actions, &time, 0.75.into()), 0.75.into());
+    }
+
+    #[test]
+    fn axis2d() {
+        let mut modifier = Stabilize::new(0.1);
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(
+            modifier.apply(&actions, &time, (0.05, 0.05).into()),
+            Vec2::ZERO.into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (1.0, 0.5).into()),
+            (1.0, 0.5).into()
+        );
+        assert_eq!(
+            modifier.apply(&actions, &time, (1.0, 0.55).into()),
+            (1.0, 0.5).into()
+        );
+    }
+
+    #[test]
+    fn bool() {
+        let mut modifier = Stabilize::default();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), true.into());
+        assert_eq!(modifier.apply(&actions, &time, false.into()), false.into());
+    }
+}
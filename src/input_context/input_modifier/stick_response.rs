@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+
+use super::InputModifier;
+use crate::{action_value::ActionValue, input_context::context_instance::ActionsData};
+
+/// Response curve for analog sticks with presets that mimic common console defaults.
+///
+/// Raises the length of the input to the power of [`Self::exp`] while preserving its direction.
+/// Unlike [`ExponentialCurve`](super::exponential_curve::ExponentialCurve), the curve is applied
+/// to the magnitude, so diagonal movement feels the same as movement along the axes.
+///
+/// Meant to be used after a dead zone, like [`RadialDeadZone`](super::radial_dead_zone::RadialDeadZone),
+/// since it expects values in the range from 0 to 1.
+///
+/// [`ActionValue::Axis1D`] is curved by its absolute value, keeping the sign.
+/// [`ActionValue::Bool`] is passed through unchanged.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StickResponse {
+    /// Curve exponent applied to the input length.
+    ///
+    /// Values above 1 give finer control around the center,
+    /// values below 1 make the stick respond faster to small movements.
+    pub exp: f32,
+}
+
+impl StickResponse {
+    #[must_use]
+    pub fn new(exp: f32) -> Self {
+        Self { exp }
+    }
+
+    /// Output matches the input.
+    ///
+    /// Half deflection gives a half of the output.
+    #[must_use]
+    pub fn linear() -> Self {
+        Self::new(1.0)
+    }
+
+    /// Quadratic curve, similar to the default feel of most console shooters.
+    ///
+    /// Half deflection gives a quarter of the output.
+    #[must_use]
+    pub fn standard() -> Self {
+        Self::new(2.0)
+    }
+
+    /// Cubic curve for slow and precise aiming around the center.
+    ///
+    /// Half deflection gives an eighth of the output.
+    #[must_use]
+    pub fn precise() -> Self {
+        Self::new(3.0)
+    }
+
+    /// Square root curve for fast turning on small movements.
+    ///
+    /// A quarter of deflection gives a half of the output.
+    #[must_use]
+    pub fn aggressive() -> Self {
+        Self::new(0.5)
+    }
+
+    fn curve(self, length: f32) -> f32 {
+        length.powf(self.exp)
+    }
+}
+
+impl Default for StickResponse {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl InputModifier for StickResponse {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(_) => value,
+            ActionValue::Axis1D(value) => self.curve(value.abs()).copysign(value).into(),
+            ActionValue::Axis2D(value) => {
+                (value.normalize_or_zero() * self.curve(value.length())).into()
+            }
+            ActionValue::Axis3D(value) => {
+                (value.normalize_or_zero() * self.curve(value.length())).into()
+            }
+        }
+    }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        axes
+    }
+
+    fn preserves_zero(&self) -> bool {
+        // Zero to the power of zero is one.
+        self.exp > 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        for (mut modifier, quarter, half) in [
+            (StickResponse::linear(), 0.25, 0.5),
+            (StickResponse::standard(), 0.0625, 0.25),
+            (StickResponse::precise(), 0.015625, 0.125),
+            (StickResponse::aggressive(), 0.5, 0.70710677),
+        ] {
+            assert_eq!(
+                modifier.apply(&actions, &time, 0.25.into()),
+                quarter.into(),
+                "{modifier:?}"
+            );
+            assert_eq!(
+                modifier.apply(&actions, &time, (-0.5).into()),
+                (-half).into(),
+                "{modifier:?}"
+            );
+            assert_eq!(
+                modifier.apply(&actions, &time, 1.0.into()),
+                1.0.into(),
+                "{modifier:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn direction() {
+        let mut modifier = StickResponse::standard();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        let value = modifier
+            .apply(&actions, &time, (0.3, 0.4).into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::new(0.15, 0.2), 1e-6));
+
+        let value = modifier
+            .apply(&actions, &time, (0.0, 0.0, -0.5).into())
+            .as_axis3d();
+        assert_eq!(value, Vec3::new(0.0, 0.0, -0.25));
+    }
+
+    #[test]
+    fn passthrough() {
+        let mut modifier = StickResponse::standard();
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        assert_eq!(modifier.apply(&actions, &time, true.into()), true.into());
+        assert_eq!(
+            modifier.apply(&actions, &time, Vec2::ZERO.into()),
+            Vec2::ZERO.into()
+        );
+    }
+}
@@ -14,6 +14,7 @@ use crate::{action_value::ActionValue, input_context::context_instance::ActionsD
 /// But for variants like [`Self::YXZ`] (where X becomes the second component), it will be
 /// converted into [`ActionValue::Axis2D`] with Y set to the value.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SwizzleAxis {
     /// Swap X and Y axis. Useful for binding 1D inputs to the Y axis for 2D actions.
     YXZ,
@@ -60,6 +61,15 @@ impl InputModifier for SwizzleAxis {
             },
         }
     }
+
+    fn nonzero_axes(&self, axes: ActionValue) -> ActionValue {
+        let mut modifier = *self;
+        modifier.apply(&ActionsData::default(), &Time::default(), axes)
+    }
+
+    fn preserves_zero(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,47 @@
+use std::{
+    any::{self, TypeId},
+    ops::Deref,
+};
+
+use bevy::{prelude::*, utils::HashMap};
+
+/// Snapshot of boolean settings registered with
+/// [`ContextAppExt::add_input_setting`](super::ContextAppExt::add_input_setting).
+///
+/// Modifiers and conditions can't access resources directly,
+/// so settings are copied here before evaluating contexts.
+/// Read them using [`ActionsData::setting`](super::context_instance::ActionsData::setting).
+#[derive(Resource, Default)]
+pub struct InputSettings(HashMap<TypeId, bool>);
+
+impl InputSettings {
+    /// Returns the snapshotted value of setting `R`.
+    pub fn get<R: Resource>(&self) -> Option<bool> {
+        self.0.get(&TypeId::of::<R>()).copied()
+    }
+}
+
+/// Copies the value of setting `R` into [`InputSettings`] when it changes.
+pub(super) fn snapshot_setting<R: Resource + Deref<Target = bool>>(
+    setting: Option<Res<R>>,
+    mut settings: ResMut<InputSettings>,
+) {
+    let type_id = TypeId::of::<R>();
+    match setting {
+        Some(setting) => {
+            if setting.is_changed() || !settings.0.contains_key(&type_id) {
+                trace!(
+                    "updating setting `{}` to `{}`",
+                    any::type_name::<R>(),
+                    **setting
+                );
+                settings.0.insert(type_id, **setting);
+            }
+        }
+        None => {
+            if settings.0.contains_key(&type_id) {
+                settings.0.remove(&type_id);
+            }
+        }
+    }
+}
@@ -1,9 +1,21 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    iter,
+    marker::PhantomData,
+};
+
 use bevy::prelude::*;
 
 use super::{
+    context_instance::ActionsData,
+    input_action::InputAction,
     input_bind::{InputBind, InputBindModCond, InputBindSet},
-    input_modifier::{negate::Negate, swizzle_axis::SwizzleAxis},
+    input_condition::{chord::Chord, pulse::Pulse},
+    input_modifier::{
+        negate::Negate, scale::Scale, scale_by::ScaleBy, swizzle_axis::SwizzleAxis, InputModifier,
+    },
 };
+use crate::{action_value::ActionValue, input::Input};
 
 /// A preset to map buttons as 2-dimensional input.
 ///
@@ -137,6 +149,134 @@ impl<I: InputBindSet> InputBindSet for Cardinal<I> {
     }
 }
 
+/// A preset to map buttons as 3-dimensional input.
+///
+/// This is a convenience preset that uses [`SwizzleAxis`] and [`Negate`] to
+/// bind the buttons to X, Y and Z axes.
+///
+/// Unlike [`Cardinal`], it follows Bevy's 3D space convention: `forward` maps to -Z,
+/// `right` to +X and `up` to +Y. So the action value can be applied to
+/// [`Transform::translation`] as is.
+///
+/// # Examples
+///
+/// Map keyboard inputs into a flying camera movement.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Component)]
+/// struct FlyCamera;
+///
+/// impl InputContext for FlyCamera {
+///     fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+///         let mut ctx = ContextInstance::default();
+///
+///         ctx.bind::<Fly>()
+///             .to((Spatial::wasd_qe_keys(), Spatial::arrow_keys()))
+///             .with_modifiers(DeltaScale::default());
+///
+///         ctx
+///     }
+/// }
+///
+/// fn apply_movement(trigger: Trigger<Fired<Fly>>, mut cameras: Query<&mut Transform>) {
+///     let mut transform = cameras.get_mut(trigger.entity()).unwrap();
+///     let rotation = transform.rotation;
+///     transform.translation += rotation * trigger.value;
+/// }
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = Vec3)]
+/// struct Fly;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Spatial<I: InputBindSet> {
+    pub forward: I,
+    pub backward: I,
+    pub left: I,
+    pub right: I,
+    pub up: I,
+    pub down: I,
+}
+
+impl Spatial<KeyCode> {
+    /// Maps WASD keys for horizontal movement, E for up and Q for down.
+    ///
+    /// See also [`Self::arrow_keys`].
+    #[must_use]
+    pub fn wasd_qe_keys() -> Self {
+        Self {
+            forward: KeyCode::KeyW,
+            backward: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            up: KeyCode::KeyE,
+            down: KeyCode::KeyQ,
+        }
+    }
+
+    /// Maps keyboard arrow keys for horizontal movement, Page Up for up and Page Down for down.
+    ///
+    /// See also [`Self::wasd_qe_keys`].
+    #[must_use]
+    pub fn arrow_keys() -> Self {
+        Self {
+            forward: KeyCode::ArrowUp,
+            backward: KeyCode::ArrowDown,
+            left: KeyCode::ArrowLeft,
+            right: KeyCode::ArrowRight,
+            up: KeyCode::PageUp,
+            down: KeyCode::PageDown,
+        }
+    }
+}
+
+impl<I: InputBindSet> InputBindSet for Spatial<I> {
+    fn bindings(self) -> impl Iterator<Item = InputBind> {
+        // -Z
+        let forward = self
+            .forward
+            .bindings()
+            .map(|binding| binding.with_modifiers((Negate::all(), SwizzleAxis::ZYX)));
+
+        // Z
+        let backward = self
+            .backward
+            .bindings()
+            .map(|binding| binding.with_modifiers(SwizzleAxis::ZYX));
+
+        // -X
+        let left = self
+            .left
+            .bindings()
+            .map(|binding| binding.with_modifiers(Negate::all()));
+
+        // X
+        let right = self.right.bindings();
+
+        // Y
+        let up = self
+            .up
+            .bindings()
+            .map(|binding| binding.with_modifiers(SwizzleAxis::YXZ));
+
+        // -Y
+        let down = self
+            .down
+            .bindings()
+            .map(|binding| binding.with_modifiers((Negate::all(), SwizzleAxis::YXZ)));
+
+        forward
+            .chain(backward)
+            .chain(left)
+            .chain(right)
+            .chain(up)
+            .chain(down)
+    }
+}
+
 /// A preset to map buttons as 2-dimensional input.
 ///
 /// Positive binding will be passed as is and negative will be reversed using [`Negate`].
@@ -194,3 +334,521 @@ impl InputBindSet for GamepadStick {
         [self.x().into(), self.y().with_modifiers(SwizzleAxis::YXZ)].into_iter()
     }
 }
+
+/// A preset to map two inputs as a single logical slot.
+///
+/// Usually used to store a keyboard or mouse input together with a gamepad input
+/// in settings, so rebinding UIs can display and edit them as a single entry.
+///
+/// Both inputs are bound to the action, so it will be triggered by any of them.
+/// If both are active at the same time, the value is determined by
+/// [`InputAction::ACCUMULATION`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Settings {
+///     jump: Dual,
+/// }
+///
+/// impl Default for Settings {
+///     fn default() -> Self {
+///         Self {
+///             jump: Dual::new(KeyCode::Space, GamepadButton::South),
+///         }
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// impl InputContext for Player {
+///     fn context_instance(world: &World, _entity: Entity) -> ContextInstance {
+///         let settings = world.resource::<Settings>();
+///
+///         let mut ctx = ContextInstance::default();
+///         ctx.bind::<Jump>().to(settings.jump);
+///         ctx
+///     }
+/// }
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Jump;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dual {
+    pub primary: Input,
+    pub secondary: Input,
+}
+
+impl Dual {
+    #[must_use]
+    pub fn new(primary: impl Into<Input>, secondary: impl Into<Input>) -> Self {
+        Self {
+            primary: primary.into(),
+            secondary: secondary.into(),
+        }
+    }
+}
+
+impl Display for Dual {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} / {}", self.primary, self.secondary)
+    }
+}
+
+impl InputBindSet for Dual {
+    fn bindings(self) -> impl Iterator<Item = InputBind> {
+        [self.primary.into(), self.secondary.into()].into_iter()
+    }
+}
+
+/// A preset to map mouse motion as 2-dimensional input only while action `A` is fired.
+///
+/// Usually `A` is bound to [`MouseButton::Middle`] to pan a camera by dragging.
+/// Uses [`Chord`], so `A` needs to be bound in the same context before the action with this preset.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Component)]
+/// struct StrategyCamera;
+///
+/// impl InputContext for StrategyCamera {
+///     fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+///         let mut ctx = ContextInstance::default();
+///
+///         ctx.bind::<Drag>().to(MouseButton::Middle);
+///         ctx.bind::<Pan>().to(DragPan::<Drag>::new(0.5));
+///
+///         ctx
+///     }
+/// }
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Drag;
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = Vec2)]
+/// struct Pan;
+/// ```
+#[derive(Debug)]
+pub struct DragPan<A: InputAction> {
+    /// Multiplier for the mouse motion.
+    pub sensitivity: f32,
+
+    /// Action that enables panning.
+    marker: PhantomData<A>,
+}
+
+impl<A: InputAction> DragPan<A> {
+    #[must_use]
+    pub fn new(sensitivity: f32) -> Self {
+        Self {
+            sensitivity,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: InputAction> Clone for DragPan<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: InputAction> Copy for DragPan<A> {}
+
+impl<A: InputAction> InputBindSet for DragPan<A> {
+    fn bindings(self) -> impl Iterator<Item = InputBind> {
+        let binding = Input::mouse_motion()
+            .with_modifiers(Scale::splat(self.sensitivity))
+            .with_conditions(Chord::<A>::default());
+
+        iter::once(binding)
+    }
+}
+
+/// A preset to map movement that is scaled while the sprint action `A` is fired.
+///
+/// Applies [`ScaleBy`] to each binding of [`Self::movement`], so any other preset,
+/// such as [`Cardinal`] or [`GamepadStick`], can be used for movement.
+/// Action `A` needs to be bound in the same context before the action with this preset.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// impl InputContext for Player {
+///     fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+///         let mut ctx = ContextInstance::default();
+///
+///         ctx.bind::<Sprint>().to(KeyCode::ShiftLeft);
+///         ctx.bind::<Move>()
+///             .to(MoveWithSprint::<Sprint, _>::new(Cardinal::wasd_keys(), 2.0));
+///
+///         ctx
+///     }
+/// }
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Sprint;
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = Vec2)]
+/// struct Move;
+/// ```
+#[derive(Debug)]
+pub struct MoveWithSprint<A: InputAction, I: InputBindSet> {
+    /// Bindings for movement.
+    pub movement: I,
+
+    /// Multiplier for the movement while sprinting.
+    pub sprint_scale: f32,
+
+    /// Action that enables sprinting.
+    marker: PhantomData<A>,
+}
+
+impl<A: InputAction, I: InputBindSet> MoveWithSprint<A, I> {
+    #[must_use]
+    pub fn new(movement: I, sprint_scale: f32) -> Self {
+        Self {
+            movement,
+            sprint_scale,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: InputAction, I: InputBindSet + Clone> Clone for MoveWithSprint<A, I> {
+    fn clone(&self) -> Self {
+        Self::new(self.movement.clone(), self.sprint_scale)
+    }
+}
+
+impl<A: InputAction, I: InputBindSet + Copy> Copy for MoveWithSprint<A, I> {}
+
+impl<A: InputAction, I: InputBindSet> InputBindSet for MoveWithSprint<A, I> {
+    fn bindings(self) -> impl Iterator<Item = InputBind> {
+        let sprint_scale = self.sprint_scale;
+        self.movement
+            .bindings()
+            .map(move |binding| binding.with_modifiers(ScaleBy::<A>::splat(sprint_scale)))
+    }
+}
+
+/// A preset with common bindings for menu navigation.
+///
+/// Each variant is intended for a separate `bool` action.
+///
+/// Directions are bound to arrow keys, WASD, D-pad and the left stick. They use [`Pulse`]
+/// with [`Self::REPEAT_INTERVAL`] to repeatedly fire while held, so observe
+/// [`Fired`](super::events::Fired) to move the selection.
+/// [`Self::Confirm`] is bound to Enter, Space and [`GamepadButton::South`].
+/// [`Self::Back`] is bound to Escape and [`GamepadButton::East`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Menu;
+///
+/// impl InputContext for Menu {
+///     fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+///         let mut ctx = ContextInstance::default();
+///
+///         ctx.bind::<Up>().to(MenuNav::Up);
+///         ctx.bind::<Down>().to(MenuNav::Down);
+///         ctx.bind::<Confirm>().to(MenuNav::Confirm);
+///
+///         ctx
+///     }
+/// }
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Up;
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Down;
+///
+/// #[derive(Debug, InputAction)]
+/// #[input_action(output = bool)]
+/// struct Confirm;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuNav {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Back,
+}
+
+impl MenuNav {
+    /// Time in seconds between each triggering while a direction is held.
+    pub const REPEAT_INTERVAL: f32 = 0.2;
+
+    fn direction(
+        keys: [KeyCode; 2],
+        button: GamepadButton,
+        axis: GamepadAxis,
+        negative: bool,
+    ) -> Vec<InputBind> {
+        let pulse = Pulse::new(Self::REPEAT_INTERVAL);
+        let stick = if negative {
+            axis.with_modifiers((Negate::all(), DiscardNegative))
+        } else {
+            axis.with_modifiers(DiscardNegative)
+        };
+
+        keys.into_iter()
+            .map(InputBind::from)
+            .chain([button.into(), stick])
+            .map(|binding| binding.with_conditions(pulse))
+            .collect()
+    }
+}
+
+impl InputBindSet for MenuNav {
+    fn bindings(self) -> impl Iterator<Item = InputBind> {
+        let bindings = match self {
+            MenuNav::Up => Self::direction(
+                [KeyCode::ArrowUp, KeyCode::KeyW],
+                GamepadButton::DPadUp,
+                GamepadAxis::LeftStickY,
+                false,
+            ),
+            MenuNav::Down => Self::direction(
+                [KeyCode::ArrowDown, KeyCode::KeyS],
+                GamepadButton::DPadDown,
+                GamepadAxis::LeftStickY,
+                true,
+            ),
+            MenuNav::Left => Self::direction(
+                [KeyCode::ArrowLeft, KeyCode::KeyA],
+                GamepadButton::DPadLeft,
+                GamepadAxis::LeftStickX,
+                true,
+            ),
+            MenuNav::Right => Self::direction(
+                [KeyCode::ArrowRight, KeyCode::KeyD],
+                GamepadButton::DPadRight,
+                GamepadAxis::LeftStickX,
+                false,
+            ),
+            MenuNav::Confirm => vec![
+                KeyCode::Enter.into(),
+                KeyCode::Space.into(),
+                GamepadButton::South.into(),
+            ],
+            MenuNav::Back => vec![KeyCode::Escape.into(), GamepadButton::East.into()],
+        };
+
+        bindings.into_iter()
+    }
+}
+
+/// Replaces negative axis values with zero.
+///
+/// Used to map only one half of a stick axis in [`MenuNav`].
+#[derive(Clone, Copy, Debug)]
+struct DiscardNegative;
+
+impl InputModifier for DiscardNegative {
+    fn apply(
+        &mut self,
+        _actions: &ActionsData,
+        _time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        let dim = value.dim();
+        ActionValue::Axis3D(value.as_axis3d().max(Vec3::ZERO)).convert(dim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spatial() {
+        let actions = ActionsData::default();
+        let time = Time::default();
+
+        for (spatial, expected_inputs) in [
+            (
+                Spatial::wasd_qe_keys(),
+                [
+                    KeyCode::KeyW,
+                    KeyCode::KeyS,
+                    KeyCode::KeyA,
+                    KeyCode::KeyD,
+                    KeyCode::KeyE,
+                    KeyCode::KeyQ,
+                ],
+            ),
+            (
+                Spatial::arrow_keys(),
+                [
+                    KeyCode::ArrowUp,
+                    KeyCode::ArrowDown,
+                    KeyCode::ArrowLeft,
+                    KeyCode::ArrowRight,
+                    KeyCode::PageUp,
+                    KeyCode::PageDown,
+                ],
+            ),
+        ] {
+            let expected_values = [
+                Vec3::NEG_Z,
+                Vec3::Z,
+                Vec3::NEG_X,
+                Vec3::X,
+                Vec3::Y,
+                Vec3::NEG_Y,
+            ];
+            let bindings: Vec<_> = spatial.bindings().collect();
+            assert_eq!(bindings.len(), expected_inputs.len());
+
+            for ((mut binding, key), expected_value) in bindings
+                .into_iter()
+                .zip(expected_inputs)
+                .zip(expected_values)
+            {
+                assert_eq!(binding.input, key.into());
+
+                let value = binding
+                    .modifiers
+                    .iter_mut()
+                    .fold(ActionValue::from(true), |value, modifier| {
+                        modifier.apply(&actions, &time, value)
+                    });
+                assert_eq!(
+                    value.as_axis3d(),
+                    expected_value,
+                    "`{key:?}` should map to `{expected_value}`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn menu_nav_directions() {
+        for (nav, expected_inputs, negative) in [
+            (
+                MenuNav::Up,
+                [
+                    KeyCode::ArrowUp.into(),
+                    KeyCode::KeyW.into(),
+                    GamepadButton::DPadUp.into(),
+                    GamepadAxis::LeftStickY.into(),
+                ],
+                false,
+            ),
+            (
+                MenuNav::Down,
+                [
+                    KeyCode::ArrowDown.into(),
+                    KeyCode::KeyS.into(),
+                    GamepadButton::DPadDown.into(),
+                    GamepadAxis::LeftStickY.into(),
+                ],
+                true,
+            ),
+            (
+                MenuNav::Left,
+                [
+                    KeyCode::ArrowLeft.into(),
+                    KeyCode::KeyA.into(),
+                    GamepadButton::DPadLeft.into(),
+                    GamepadAxis::LeftStickX.into(),
+                ],
+                true,
+            ),
+            (
+                MenuNav::Right,
+                [
+                    KeyCode::ArrowRight.into(),
+                    KeyCode::KeyD.into(),
+                    GamepadButton::DPadRight.into(),
+                    GamepadAxis::LeftStickX.into(),
+                ],
+                false,
+            ),
+        ] {
+            let bindings: Vec<_> = nav.bindings().collect();
+            let inputs: Vec<Input> = bindings.iter().map(|binding| binding.input).collect();
+            assert_eq!(inputs, expected_inputs, "inputs for `{nav:?}`");
+
+            for binding in &bindings {
+                assert_eq!(binding.conditions.len(), 1);
+                let condition = format!("{:?}", binding.conditions[0]);
+                assert!(
+                    condition.starts_with("Pulse"),
+                    "`{nav:?}` should repeat while held"
+                );
+            }
+
+            let mut stick = bindings.into_iter().last().unwrap();
+            let actions = ActionsData::default();
+            let time = Time::default();
+            let value = stick
+                .modifiers
+                .iter_mut()
+                .fold(ActionValue::from(1.0), |value, modifier| {
+                    modifier.apply(&actions, &time, value)
+                });
+            let expected = if negative { 0.0 } else { 1.0 };
+            assert_eq!(
+                value,
+                expected.into(),
+                "`{nav:?}` should use only one half of the stick axis"
+            );
+        }
+    }
+
+    #[test]
+    fn menu_nav_confirm_back() {
+        let inputs: Vec<Input> = MenuNav::Confirm
+            .bindings()
+            .map(|binding| binding.input)
+            .collect();
+        assert_eq!(
+            inputs,
+            [
+                KeyCode::Enter.into(),
+                KeyCode::Space.into(),
+                GamepadButton::South.into()
+            ]
+        );
+
+        let inputs: Vec<Input> = MenuNav::Back
+            .bindings()
+            .map(|binding| binding.input)
+            .collect();
+        assert_eq!(inputs, [KeyCode::Escape.into(), GamepadButton::East.into()]);
+    }
+}
@@ -23,6 +23,9 @@ like [`DeadZone`], [`Negate`], etc., but you can add your own by implementing [`
 [`Conditions`](input_context::input_condition) define how an action activates. We also provide built-in conditions, such as [`Press`],
 [`Release`], [`Hold`], etc. You can also add your own by implementing [`InputCondition`].
 
+Built-in modifiers, conditions and [`presets`](input_context::preset) can be disabled with the `builtin_modifiers`,
+`builtin_conditions` and `presets` cargo features respectively. All of them are enabled by default.
+
 # Quick start
 
 We provide a [`prelude`] module, which exports most of the typically used traits and types.
@@ -63,33 +66,56 @@ Alternatively you can configure [`LogPlugin`](bevy::log::LogPlugin) to make it p
 extern crate self as bevy_enhanced_input;
 
 pub mod action_value;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
 pub mod input;
 pub mod input_context;
 
 pub mod prelude {
     pub use super::{
         action_value::{ActionValue, ActionValueDim},
-        input::{GamepadDevice, Input, InputModKeys, ModKeys},
+        input::{
+            gamepad_join::{GamepadJoin, GamepadJoinPlugin, PlayerJoined},
+            AnyGamepadReduce, AnyInput, GamepadDevice, Input, InputModKeys, ModKeys,
+            MouseScrollNormalization,
+        },
         input_context::{
+            action_mock::{ActionMock, MockDirectives, MockSpan},
+            action_read::ActionRead,
+            common_conditions::*,
             context_instance::{ActionBind, ActionData, ActionState, ContextInstance},
             events::*,
             input_action::{Accumulation, InputAction},
             input_bind::{InputBind, InputBindModCond, InputBindSet},
-            input_condition::{
-                block_by::*, chord::*, condition_timer::*, hold::*, hold_and_release::*,
-                just_press::*, press::*, pulse::*, release::*, tap::*, ConditionKind,
-                InputCondition,
-            },
-            input_modifier::{
-                accumulate_by::*, dead_zone::*, delta_scale::*, exponential_curve::*, negate::*,
-                scale::*, smooth_nudge::*, swizzle_axis::*, InputModifier,
-            },
-            preset::{Bidirectional, Cardinal, GamepadStick},
-            ContextAppExt, ContextInstances, InputContext, RebuildInputContexts,
+            input_condition::{ConditionKind, InputCondition},
+            input_modifier::InputModifier,
+            input_setting::InputSettings,
+            ContextActivated, ContextAppExt, ContextDeactivated, ContextInstances, InputContext,
+            InputEpoch, RebuildInputContexts,
         },
         EnhancedInputPlugin, EnhancedInputSystem,
     };
     pub use bevy_enhanced_input_macros::InputAction;
+
+    #[cfg(feature = "builtin_conditions")]
+    pub use super::input_context::input_condition::{
+        block_by::*, charge_fire::*, chord::*, combo::*, condition_timer::*, double_click::*,
+        hold::*, hold_and_release::*, just_press::*, not::*, press::*, pulse::*, release::*,
+        release_window::*, tap::*, toggle::*, value_changed::*, when_entity_action::*,
+        window_focused::*,
+    };
+    #[cfg(feature = "builtin_modifiers")]
+    pub use super::input_context::input_modifier::{
+        accumulate_by::*, asymmetric_gain::*, clamp_magnitude::*, clamp_then_normalize::*,
+        dead_zone::*, delta_scale::*, derivative::*, exponential_curve::*, lookup_table::*,
+        momentum::*, negate::*, normalize::*, notch::*, radial_dead_zone::*, response_curve::*,
+        scale::*, scale_by::*, setting_negate::*, smooth_damp::*, smooth_nudge::*, stabilize::*,
+        stick_response::*, swizzle_axis::*,
+    };
+    #[cfg(feature = "presets")]
+    pub use super::input_context::preset::{
+        Bidirectional, Cardinal, DragPan, Dual, GamepadStick, MenuNav, MoveWithSprint, Spatial,
+    };
 }
 
 use bevy::{input::InputSystem, prelude::*};
@@ -104,20 +130,40 @@ impl Plugin for EnhancedInputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ContextInstances>()
             .init_resource::<ResetInput>()
+            .init_resource::<AnyInput>()
+            .init_resource::<InputEpoch>()
+            .init_resource::<AnyGamepadReduce>()
+            .init_resource::<MouseScrollNormalization>()
+            .init_resource::<InputSettings>()
+            .init_resource::<MockDirectives>()
             .configure_sets(PreUpdate, EnhancedInputSystem.after(InputSystem))
-            .add_systems(PreUpdate, Self::update.in_set(EnhancedInputSystem));
+            .add_systems(
+                PreUpdate,
+                (Self::advance_epoch, Self::update)
+                    .chain()
+                    .in_set(EnhancedInputSystem),
+            );
     }
 }
 
 impl EnhancedInputPlugin {
+    fn advance_epoch(mut epoch: ResMut<InputEpoch>) {
+        epoch.0 = epoch.0.wrapping_add(1);
+    }
+
     fn update(
         mut commands: Commands,
         mut reader: InputReader,
         time: Res<Time<Virtual>>, // We explicitly use `Virtual` to have access to `relative_speed`.
         mut instances: ResMut<ContextInstances>,
+        mut any_input: ResMut<AnyInput>,
+        settings: Res<InputSettings>,
+        directives: Res<MockDirectives>,
     ) {
         reader.update_state();
-        instances.update(&mut commands, &mut reader, &time);
+        any_input.0 = reader.any_input();
+        instances.apply_directives(&directives);
+        instances.update(&mut commands, &mut reader, &time, &settings);
     }
 }
 
@@ -47,6 +47,34 @@ fn cumulative() {
     );
 }
 
+#[test]
+fn bool_or() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::KeyZ);
+    keys.press(KeyCode::KeyX);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    let action = ctx.action::<CumulativeBool>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(action.value(), true.into());
+    assert_eq!(
+        action.value().as_axis1d(),
+        1.0,
+        "pressed bindings shouldn't be summed"
+    );
+}
+
 #[derive(Debug, Component)]
 struct DummyContext;
 
@@ -56,6 +84,8 @@ impl InputContext for DummyContext {
 
         ctx.bind::<MaxAbs>().to(Cardinal::wasd_keys());
         ctx.bind::<Cumulative>().to(Cardinal::arrow_keys());
+        ctx.bind::<CumulativeBool>()
+            .to((KeyCode::KeyZ, KeyCode::KeyX, KeyCode::KeyC));
 
         ctx
     }
@@ -68,3 +98,7 @@ struct MaxAbs;
 #[derive(Debug, InputAction)]
 #[input_action(output = Vec2, accumulation = Cumulative)]
 struct Cumulative;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, accumulation = Cumulative)]
+struct CumulativeBool;
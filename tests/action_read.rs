@@ -0,0 +1,85 @@
+use bevy::{ecs::system::RunSystemOnce, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn single() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<LastState>()
+        .add_systems(
+            Update,
+            |action: ActionRead<DummyContext, DummyAction>, mut last_state: ResMut<LastState>| {
+                **last_state = action.single().state();
+            },
+        );
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<LastState>(), ActionState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<LastState>(), ActionState::Fired);
+}
+
+#[test]
+fn get() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity1 = app.world_mut().spawn(DummyContext).id();
+    let entity2 = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .run_system_once(move |action: ActionRead<DummyContext, DummyAction>| {
+            assert!(
+                action.get_single().is_none(),
+                "should have multiple entities"
+            );
+            assert_eq!(action.get(entity1).unwrap().state(), ActionState::Fired);
+            assert_eq!(
+                action.get(entity2).unwrap().state(),
+                ActionState::None,
+                "input should be consumed by the first entity"
+            );
+        })
+        .unwrap();
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct LastState(ActionState);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to(DummyAction::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
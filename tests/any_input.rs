@@ -0,0 +1,54 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn keyboard() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin));
+
+    app.update();
+
+    assert!(!**app.world().resource::<AnyInput>());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyA);
+
+    app.update();
+
+    assert!(**app.world().resource::<AnyInput>());
+
+    app.update();
+
+    assert!(
+        **app.world().resource::<AnyInput>(),
+        "held input should be considered active"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::KeyA);
+
+    app.update();
+
+    assert!(!**app.world().resource::<AnyInput>());
+}
+
+#[test]
+fn gamepad() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin));
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.update();
+
+    assert!(!**app.world().resource::<AnyInput>());
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.digital_mut().press(GamepadButton::South);
+
+    app.update();
+
+    assert!(**app.world().resource::<AnyInput>());
+}
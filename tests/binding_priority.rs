@@ -0,0 +1,85 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn tie() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(LOW_KEY);
+    keys.press(HIGH_KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(
+        action.value(),
+        HIGH_VALUE.into(),
+        "value of the binding with a higher priority should be used"
+    );
+}
+
+#[test]
+fn fallback() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(LOW_KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.value(),
+        LOW_VALUE.into(),
+        "binding with a lower priority should be used when others are inactive"
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<DummyAction>().to((
+            LOW_KEY.with_modifiers(Scale::splat(LOW_VALUE)),
+            HIGH_KEY
+                .with_modifiers(Scale::splat(HIGH_VALUE))
+                .with_priority(1),
+        ));
+
+        ctx
+    }
+}
+
+const LOW_KEY: KeyCode = KeyCode::KeyA;
+const HIGH_KEY: KeyCode = KeyCode::KeyB;
+
+const LOW_VALUE: f32 = 1.0;
+const HIGH_VALUE: f32 = 0.5;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct DummyAction;
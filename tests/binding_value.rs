@@ -0,0 +1,103 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn tracking() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyA);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    let values: Vec<_> = ctx
+        .action_bind::<Tracked>()
+        .bindings()
+        .iter()
+        .map(InputBind::value)
+        .collect();
+    assert_eq!(
+        values,
+        [Some(false.into()), Some(true.into())],
+        "values should be stored before modifiers"
+    );
+    assert_eq!(ctx.action::<Tracked>().value(), (-1.0).into());
+
+    let action_bind = ctx.action_bind::<Untracked>();
+    assert!(action_bind
+        .bindings()
+        .iter()
+        .all(|binding| binding.value().is_none()));
+}
+
+#[test]
+fn disabled() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyA);
+
+    app.update();
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    let action_bind = instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<Tracked>();
+    assert!(action_bind.bindings()[0].value().is_some());
+
+    action_bind.set_enabled(false);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action_bind = instances
+        .context::<DummyContext>(entity)
+        .action_bind::<Tracked>();
+    assert!(
+        action_bind
+            .bindings()
+            .iter()
+            .all(|binding| binding.value().is_none()),
+        "values shouldn't be stored for bindings that weren't read"
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Tracked>()
+            .with_value_tracking(true)
+            .to(Bidirectional {
+                positive: KeyCode::KeyD,
+                negative: KeyCode::KeyA,
+            });
+        ctx.bind::<Untracked>().to(KeyCode::KeyA);
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Tracked;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Untracked;
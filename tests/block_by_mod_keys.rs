@@ -0,0 +1,96 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn blocked() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::ControlLeft);
+    keys.press(KeyCode::KeyS);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Save>().state(), ActionState::Fired);
+    assert_eq!(
+        ctx.action::<MoveBack>().state(),
+        ActionState::None,
+        "should be blocked by the action with more modifiers"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::ControlLeft);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Save>().state(), ActionState::None);
+    assert_eq!(ctx.action::<MoveBack>().state(), ActionState::Fired);
+}
+
+#[test]
+fn other_key() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::ControlLeft);
+    keys.press(KeyCode::KeyS);
+    keys.press(KeyCode::KeyD);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Save>().state(), ActionState::Fired);
+    assert_eq!(ctx.action::<MoveBack>().state(), ActionState::None);
+    assert_eq!(
+        ctx.action::<MoveRight>().state(),
+        ActionState::Fired,
+        "only bindings with the same key should be blocked"
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        // Bound first intentionally to ensure that the order doesn't matter.
+        ctx.bind::<MoveBack>().to(KeyCode::KeyS);
+        ctx.bind::<MoveRight>().to(KeyCode::KeyD);
+        ctx.bind::<Save>()
+            .to(KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL));
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false, block_by_mod_keys = true)]
+struct MoveBack;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false, block_by_mod_keys = true)]
+struct MoveRight;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false, block_by_mod_keys = true)]
+struct Save;
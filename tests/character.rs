@@ -0,0 +1,197 @@
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn layout() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    // On AZERTY the physical `W` key produces `z`.
+    app.world_mut()
+        .send_event(key_event(KeyCode::KeyW, 'z', ButtonState::Pressed));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Undo>().state(), ActionState::Fired);
+    assert_eq!(
+        ctx.action::<Physical>().state(),
+        ActionState::None,
+        "physical binding shouldn't react to the produced character"
+    );
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Undo>().state(),
+        ActionState::Fired,
+        "character should stay active while the key is held"
+    );
+
+    app.world_mut()
+        .send_event(key_event(KeyCode::KeyW, 'z', ButtonState::Released));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Undo>().state(), ActionState::None);
+}
+
+#[test]
+fn other_character() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    // On QWERTZ the physical `Z` key produces `y`.
+    app.world_mut()
+        .send_event(key_event(Physical::KEY, 'y', ButtonState::Pressed));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Undo>().state(), ActionState::None);
+    assert_eq!(ctx.action::<Physical>().state(), ActionState::Fired);
+}
+
+#[test]
+fn layering() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<CharacterContext>()
+        .add_input_context::<KeyContext>();
+
+    let entity = app.world_mut().spawn((CharacterContext, KeyContext)).id();
+
+    app.update();
+
+    app.world_mut()
+        .send_event(key_event(KeyCode::KeyW, 'z', ButtonState::Pressed));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    assert_eq!(
+        instances
+            .context::<CharacterContext>(entity)
+            .action::<Undo>()
+            .state(),
+        ActionState::Fired
+    );
+    assert_eq!(
+        instances
+            .context::<KeyContext>(entity)
+            .action::<Forward>()
+            .state(),
+        ActionState::None,
+        "key should be consumed together with the character it produced"
+    );
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances.set_priority::<KeyContext>(CharacterContext::PRIORITY + 1);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    assert_eq!(
+        instances
+            .context::<KeyContext>(entity)
+            .action::<Forward>()
+            .state(),
+        ActionState::Fired
+    );
+    assert_eq!(
+        instances
+            .context::<CharacterContext>(entity)
+            .action::<Undo>()
+            .state(),
+        ActionState::None,
+        "character should be consumed together with the key that produced it"
+    );
+}
+
+fn key_event(key_code: KeyCode, character: char, state: ButtonState) -> KeyboardInput {
+    KeyboardInput {
+        key_code,
+        logical_key: Key::Character(character.to_string().into()),
+        state,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    }
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Undo>().to(Undo::CHARACTER);
+        ctx.bind::<Physical>().to(Physical::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, Component)]
+struct CharacterContext;
+
+impl InputContext for CharacterContext {
+    const PRIORITY: isize = 1;
+
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Undo>().to(Undo::CHARACTER);
+        ctx
+    }
+}
+
+#[derive(Debug, Component)]
+struct KeyContext;
+
+impl InputContext for KeyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Forward>().to(KeyCode::KeyW);
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Undo;
+
+impl Undo {
+    const CHARACTER: char = 'z';
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Physical;
+
+impl Physical {
+    const KEY: KeyCode = KeyCode::KeyZ;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Forward;
@@ -0,0 +1,116 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn fired() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<RunCount>()
+        .add_systems(
+            Update,
+            count_runs.run_if(action_fired::<DummyContext, DummyAction>()),
+        );
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<RunCount>(), 0);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<RunCount>(),
+        2,
+        "should run while the action is firing"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(DummyAction::KEY);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<RunCount>(), 2);
+}
+
+#[test]
+fn started() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<RunCount>()
+        .add_systems(
+            Update,
+            count_runs.run_if(action_started::<DummyContext, DummyAction>()),
+        );
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<RunCount>(),
+        1,
+        "should run only when the action starts"
+    );
+}
+
+#[test]
+fn missing_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<RunCount>()
+        .add_systems(
+            Update,
+            count_runs.run_if(action_fired::<DummyContext, DummyAction>()),
+        );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<RunCount>(), 0);
+}
+
+fn count_runs(mut count: ResMut<RunCount>) {
+    **count += 1;
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct RunCount(usize);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to(DummyAction::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
@@ -0,0 +1,100 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn activation_order() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<OnFoot>()
+        .add_input_context::<InCar>()
+        .init_resource::<Activations>()
+        .add_observer(record_activation::<OnFoot>)
+        .add_observer(record_activation::<InCar>)
+        .add_observer(record_deactivation::<OnFoot>)
+        .add_observer(record_deactivation::<InCar>);
+
+    let entity = app.world_mut().spawn(OnFoot).id();
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<OnFoot>()
+        .insert(InCar);
+
+    app.update();
+
+    let activations = app.world().resource::<Activations>();
+    assert_eq!(
+        **activations,
+        [
+            ("OnFoot activated", entity, OnFoot::PRIORITY),
+            ("OnFoot deactivated", entity, OnFoot::PRIORITY),
+            ("InCar activated", entity, InCar::PRIORITY),
+        ]
+    );
+}
+
+#[test]
+fn unregistered() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Activations>()
+        .add_observer(record_activation::<OnFoot>);
+
+    app.world_mut().spawn(OnFoot);
+
+    app.update();
+
+    let activations = app.world().resource::<Activations>();
+    assert!(activations.is_empty());
+}
+
+fn record_activation<C: InputContext + Named>(
+    trigger: Trigger<ContextActivated<C>>,
+    mut activations: ResMut<Activations>,
+) {
+    activations.push((C::ACTIVATED, trigger.entity(), trigger.priority));
+}
+
+fn record_deactivation<C: InputContext + Named>(
+    trigger: Trigger<ContextDeactivated<C>>,
+    mut activations: ResMut<Activations>,
+) {
+    activations.push((C::DEACTIVATED, trigger.entity(), trigger.priority));
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Activations(Vec<(&'static str, Entity, isize)>);
+
+trait Named {
+    const ACTIVATED: &'static str;
+    const DEACTIVATED: &'static str;
+}
+
+#[derive(Debug, Component)]
+struct OnFoot;
+
+impl InputContext for OnFoot {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        ContextInstance::default()
+    }
+}
+
+impl Named for OnFoot {
+    const ACTIVATED: &'static str = "OnFoot activated";
+    const DEACTIVATED: &'static str = "OnFoot deactivated";
+}
+
+#[derive(Debug, Component)]
+struct InCar;
+
+impl InputContext for InCar {
+    const PRIORITY: isize = 1;
+
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        ContextInstance::default()
+    }
+}
+
+impl Named for InCar {
+    const ACTIVATED: &'static str = "InCar activated";
+    const DEACTIVATED: &'static str = "InCar deactivated";
+}
@@ -71,6 +71,58 @@ fn by_id() {
     assert_eq!(ctx.action::<DummyAction>().state(), ActionState::None);
 }
 
+#[test]
+fn keyboard_and_gamepad_split() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<SplitPlayer>();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    let keyboard_player = app.world_mut().spawn(SplitPlayer(None)).id();
+    let gamepad_player = app
+        .world_mut()
+        .spawn(SplitPlayer(Some(gamepad_entity)))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(SplitAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<SplitPlayer>(keyboard_player);
+    assert_eq!(ctx.action::<SplitAction>().state(), ActionState::Fired);
+    let ctx = instances.context::<SplitPlayer>(gamepad_player);
+    assert_eq!(
+        ctx.action::<SplitAction>().state(),
+        ActionState::None,
+        "gamepad player shouldn't read keyboard"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(SplitAction::KEY);
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.digital_mut().press(SplitAction::BUTTON);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<SplitPlayer>(keyboard_player);
+    assert_eq!(
+        ctx.action::<SplitAction>().state(),
+        ActionState::None,
+        "keyboard player shouldn't read gamepads"
+    );
+    let ctx = instances.context::<SplitPlayer>(gamepad_player);
+    assert_eq!(ctx.action::<SplitAction>().state(), ActionState::Fired);
+}
+
 #[derive(Debug, Component)]
 struct AnyGamepad;
 
@@ -97,6 +149,28 @@ impl InputContext for SingleGamepad {
     }
 }
 
+/// Uses only keyboard without a gamepad and only the gamepad otherwise.
+#[derive(Debug, Component, Deref)]
+struct SplitPlayer(Option<Entity>);
+
+impl InputContext for SplitPlayer {
+    fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        match **world.get::<Self>(entity).unwrap() {
+            Some(gamepad_entity) => {
+                ctx.set_gamepad(gamepad_entity);
+                ctx.set_keyboard_mouse(false);
+            }
+            None => ctx.set_gamepad(GamepadDevice::None),
+        }
+        ctx.bind::<SplitAction>()
+            .to((SplitAction::KEY, SplitAction::BUTTON));
+
+        ctx
+    }
+}
+
 #[derive(Debug, InputAction)]
 #[input_action(output = bool)]
 struct DummyAction;
@@ -104,3 +178,13 @@ struct DummyAction;
 impl DummyAction {
     const BUTTON: GamepadButton = GamepadButton::South;
 }
+
+/// Doesn't consume to ensure that players are isolated by their devices.
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct SplitAction;
+
+impl SplitAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+    const BUTTON: GamepadButton = GamepadButton::South;
+}
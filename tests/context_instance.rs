@@ -55,6 +55,198 @@ fn rebuild() {
     );
 }
 
+#[test]
+fn rebuild_events() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<CompletedCount>()
+        .add_observer(
+            |_trigger: Trigger<Completed<DummyAction>>, mut count: ResMut<CompletedCount>| {
+                **count += 1;
+            },
+        );
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    app.world_mut().trigger(RebuildInputContexts);
+
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<CompletedCount>(),
+        1,
+        "held action should transition to none on rebuild"
+    );
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<DummyAction>().state(),
+        ActionState::Fired,
+        "held input should be re-evaluated on the next update"
+    );
+}
+
+#[test]
+fn targeted_rebuild() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity1 = app.world_mut().spawn(DummyContext).id();
+    let entity2 = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(OtherAction::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .trigger_targets(RebuildInputContexts, entity2);
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity1);
+    assert_eq!(
+        ctx.action::<OtherAction>().state(),
+        ActionState::Fired,
+        "only the targeted entity should be rebuilt"
+    );
+
+    app.world_mut()
+        .trigger_targets(RebuildInputContexts, entity1);
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity1);
+    assert_eq!(ctx.action::<OtherAction>().state(), ActionState::None);
+}
+
+#[test]
+fn fired_actions() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    assert_eq!(instances.fired_actions(entity).count(), 0);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let fired: Vec<_> = instances.fired_actions(entity).collect();
+    assert_eq!(fired, [std::any::type_name::<DummyAction>()]);
+}
+
+#[test]
+fn snapshot() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    let snapshot = ctx.snapshot();
+    assert_eq!(snapshot.len(), 2);
+
+    let dummy = ctx.action::<DummyAction>();
+    assert_eq!(
+        snapshot[std::any::type_name::<DummyAction>()],
+        (dummy.state(), dummy.value())
+    );
+    assert_eq!(dummy.state(), ActionState::Fired);
+
+    let other = ctx.action::<OtherAction>();
+    assert_eq!(
+        snapshot[std::any::type_name::<OtherAction>()],
+        (other.state(), other.value())
+    );
+}
+
+#[test]
+fn custom_name() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action_bind = instances
+        .context::<DummyContext>(entity)
+        .action_bind::<OtherAction>();
+    assert_eq!(action_bind.name(), OtherAction::NAME);
+    assert_eq!(
+        action_bind.action_name(),
+        std::any::type_name::<OtherAction>()
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(OtherAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let fired: Vec<_> = instances.fired_actions(entity).collect();
+    assert_eq!(fired, [OtherAction::NAME]);
+}
+
+#[test]
+fn action_bindings() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<MultiBindContext>();
+
+    let entity = app.world_mut().spawn(MultiBindContext).id();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let inputs: Vec<_> = instances
+        .action_bindings::<DummyAction>(entity)
+        .map(|binding| binding.input)
+        .collect();
+    assert_eq!(
+        inputs,
+        [DummyAction::KEY.into(), GamepadButton::South.into()]
+    );
+    assert_eq!(instances.action_bindings::<OtherAction>(entity).count(), 0);
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct CompletedCount(usize);
+
 #[derive(Debug, Component)]
 struct DummyContext;
 
@@ -62,6 +254,21 @@ impl InputContext for DummyContext {
     fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
         let mut ctx = ContextInstance::default();
         ctx.bind::<DummyAction>().to(DummyAction::KEY);
+        ctx.bind::<OtherAction>()
+            .with_name(OtherAction::NAME)
+            .to(OtherAction::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, Component)]
+struct MultiBindContext;
+
+impl InputContext for MultiBindContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>()
+            .to((DummyAction::KEY, GamepadButton::South));
         ctx
     }
 }
@@ -73,3 +280,12 @@ struct DummyAction;
 impl DummyAction {
     const KEY: KeyCode = KeyCode::KeyA;
 }
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct OtherAction;
+
+impl OtherAction {
+    const NAME: &str = "Other Action";
+    const KEY: KeyCode = KeyCode::KeyB;
+}
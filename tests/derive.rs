@@ -0,0 +1,82 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn sum() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Total>().state(), ActionState::None);
+    assert_eq!(ctx.action::<Total>().value(), 0.0.into());
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(First::KEY);
+    keys.press(Second::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Total>().state(), ActionState::Fired);
+    assert_eq!(
+        ctx.action::<Total>().value(),
+        3.0.into(),
+        "should read values of sources from the same update"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(First::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Total>().value(), 2.0.into());
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        // Bound before its sources to ensure that the order doesn't matter.
+        ctx.bind::<Total>().derive_from(|actions| {
+            let first = actions.action::<First>().unwrap().value().as_axis1d();
+            let second = actions.action::<Second>().unwrap().value().as_axis1d();
+            first + second
+        });
+        ctx.bind::<First>().to(First::KEY);
+        ctx.bind::<Second>()
+            .to(Second::KEY.with_modifiers(Scale::splat(2.0)));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Total;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct First;
+
+impl First {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Second;
+
+impl Second {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
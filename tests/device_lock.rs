@@ -0,0 +1,81 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn lock() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(GamepadAxis::LeftStickX, 1.0);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Move>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(action.value(), Vec2::X.into());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Move>();
+    assert_eq!(
+        action.value(),
+        Vec2::X.into(),
+        "keyboard should be ignored while the stick is held"
+    );
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(GamepadAxis::LeftStickX, 0.0);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Move>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(
+        action.value(),
+        Vec2::Y.into(),
+        "keyboard should take over after the stick release"
+    );
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(GamepadAxis::LeftStickX, 1.0);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Move>();
+    assert_eq!(
+        action.value(),
+        Vec2::Y.into(),
+        "stick should be ignored while the key is held"
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Move>()
+            .to((Cardinal::wasd_keys(), GamepadStick::Left));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2, device_lock = true)]
+struct Move;
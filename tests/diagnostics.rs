@@ -0,0 +1,108 @@
+use bevy::{
+    diagnostic::{DiagnosticPath, DiagnosticsStore},
+    input::InputPlugin,
+    prelude::*,
+};
+use bevy_enhanced_input::{diagnostics::InputDiagnosticsPlugin, prelude::*};
+
+#[test]
+fn registration() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        InputDiagnosticsPlugin,
+    ));
+
+    let diagnostics = app.world().resource::<DiagnosticsStore>();
+    for path in [
+        InputDiagnosticsPlugin::ACTIONS,
+        InputDiagnosticsPlugin::BINDINGS,
+        InputDiagnosticsPlugin::CONSUMED_INPUTS,
+        InputDiagnosticsPlugin::EVENTS,
+    ] {
+        assert!(
+            diagnostics.get(&path).is_some(),
+            "`{path}` should be registered"
+        );
+    }
+}
+
+#[test]
+fn measurements() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        InputDiagnosticsPlugin,
+    ))
+    .add_input_context::<DummyContext>();
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    assert_eq!(measurement(&app, InputDiagnosticsPlugin::ACTIONS), 2.0);
+    assert_eq!(measurement(&app, InputDiagnosticsPlugin::BINDINGS), 2.0);
+    assert_eq!(
+        measurement(&app, InputDiagnosticsPlugin::CONSUMED_INPUTS),
+        0.0
+    );
+    assert_eq!(measurement(&app, InputDiagnosticsPlugin::EVENTS), 0.0);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Consume::KEY);
+
+    app.update();
+
+    assert_eq!(measurement(&app, InputDiagnosticsPlugin::ACTIONS), 2.0);
+    assert_eq!(measurement(&app, InputDiagnosticsPlugin::BINDINGS), 2.0);
+    assert_eq!(
+        measurement(&app, InputDiagnosticsPlugin::CONSUMED_INPUTS),
+        1.0
+    );
+    assert_eq!(
+        measurement(&app, InputDiagnosticsPlugin::EVENTS),
+        2.0,
+        "should count `Started` and `Fired`"
+    );
+}
+
+fn measurement(app: &App, path: DiagnosticPath) -> f64 {
+    app.world()
+        .resource::<DiagnosticsStore>()
+        .get_measurement(&path)
+        .expect("diagnostic should have a measurement")
+        .value
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Consume>().to(Consume::KEY);
+        ctx.bind::<Passthrough>().to(Passthrough::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = true)]
+struct Consume;
+
+impl Consume {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Passthrough;
+
+impl Passthrough {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
@@ -125,6 +125,31 @@ fn axis3d() {
     assert_eq!(ctx.action::<Axis3D>().value(), Vec3::ZERO.into());
 }
 
+#[test]
+fn bool_threshold() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Threshold::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Threshold>().value(),
+        false.into(),
+        "value below the threshold should be converted into `false`"
+    );
+}
+
 #[derive(Debug, Component)]
 struct DummyContext;
 
@@ -136,6 +161,9 @@ impl InputContext for DummyContext {
         ctx.bind::<Axis1D>().to(Axis1D::KEY);
         ctx.bind::<Axis2D>().to(Axis2D::KEY);
         ctx.bind::<Axis3D>().to(Axis3D::KEY);
+        ctx.bind::<Threshold>()
+            .with_bool_threshold(0.3)
+            .to(Threshold::KEY.with_modifiers(Scale::splat(0.05)));
 
         ctx
     }
@@ -172,3 +200,11 @@ struct Axis3D;
 impl Axis3D {
     const KEY: KeyCode = KeyCode::KeyD;
 }
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Threshold;
+
+impl Threshold {
+    const KEY: KeyCode = KeyCode::KeyE;
+}
@@ -0,0 +1,85 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn once_per_activation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<FiredCount>()
+        .add_observer(
+            |_trigger: Trigger<Fired<EdgeTriggered>>, mut count: ResMut<FiredCount>| {
+                **count += 1;
+            },
+        );
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(**app.world().resource::<FiredCount>(), 1);
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<EdgeTriggered>().state(), ActionState::Ongoing);
+    assert_eq!(
+        ctx.action::<Continuous>().state(),
+        ActionState::Fired,
+        "regular actions should keep firing"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<EdgeTriggered>().state(), ActionState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<FiredCount>(),
+        2,
+        "should fire again after reactivation"
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct FiredCount(usize);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<EdgeTriggered>().to(KEY);
+        ctx.bind::<Continuous>().to(KEY);
+        ctx
+    }
+}
+
+const KEY: KeyCode = KeyCode::KeyA;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false, edge_triggered = true)]
+struct EdgeTriggered;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct Continuous;
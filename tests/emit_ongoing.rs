@@ -0,0 +1,100 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn emit() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<OngoingCount>()
+        .add_observer(
+            |_trigger: Trigger<Ongoing<Emitting>>, mut count: ResMut<OngoingCount>| {
+                **count += 1;
+            },
+        );
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+    app.update();
+
+    assert_eq!(**app.world().resource::<OngoingCount>(), 2);
+}
+
+#[test]
+fn suppress() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<OngoingCount>()
+        .init_resource::<StartedCount>()
+        .add_observer(
+            |_trigger: Trigger<Ongoing<Silent>>, mut count: ResMut<OngoingCount>| {
+                **count += 1;
+            },
+        )
+        .add_observer(
+            |_trigger: Trigger<Started<Silent>>, mut count: ResMut<StartedCount>| {
+                **count += 1;
+            },
+        );
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+    app.update();
+
+    assert_eq!(**app.world().resource::<OngoingCount>(), 0);
+    assert_eq!(
+        **app.world().resource::<StartedCount>(),
+        1,
+        "other events should be triggered"
+    );
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Silent>();
+    assert_eq!(action.state(), ActionState::Ongoing);
+    assert_eq!(action.events(), ActionEvents::ONGOING);
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct OngoingCount(usize);
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct StartedCount(usize);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Emitting>()
+            .to(KEY)
+            .with_conditions(Hold::new(1.0));
+        ctx.bind::<Silent>().to(KEY).with_conditions(Hold::new(1.0));
+        ctx
+    }
+}
+
+const KEY: KeyCode = KeyCode::KeyA;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct Emitting;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false, emit_ongoing = false)]
+struct Silent;
@@ -0,0 +1,204 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn disable_fired() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<TriggeredEvents>()
+        .add_observer(completed)
+        .add_observer(canceled);
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(action.state(), ActionState::Fired);
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    let action_bind = instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<DummyAction>()
+        .set_enabled(false);
+    assert!(!action_bind.is_enabled());
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "disabled action should ignore held inputs"
+    );
+
+    let events = app.world().resource::<TriggeredEvents>();
+    assert_eq!(events.completed, 1);
+    assert_eq!(events.canceled, 0);
+}
+
+#[test]
+fn disable_ongoing() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<TriggeredEvents>()
+        .add_observer(completed)
+        .add_observer(canceled);
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(HoldAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<HoldAction>();
+    assert_eq!(action.state(), ActionState::Ongoing);
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<HoldAction>()
+        .set_enabled(false);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<HoldAction>();
+    assert_eq!(action.state(), ActionState::None);
+
+    let events = app.world().resource::<TriggeredEvents>();
+    assert_eq!(events.completed, 0);
+    assert_eq!(events.canceled, 1);
+}
+
+#[test]
+fn reenable() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    let ctx = instances.context_mut::<DummyContext>(entity);
+    ctx.bind::<DummyAction>().set_enabled(false);
+    ctx.bind::<ResetAction>().set_enabled(false);
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(DummyAction::KEY);
+    keys.press(ResetAction::KEY);
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    let ctx = instances.context_mut::<DummyContext>(entity);
+    ctx.bind::<DummyAction>().set_enabled(true);
+    ctx.bind::<ResetAction>().set_enabled(true);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<DummyAction>().state(), ActionState::Fired);
+    assert_eq!(
+        ctx.action::<ResetAction>().state(),
+        ActionState::None,
+        "held input should be ignored until released"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(ResetAction::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(ResetAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<ResetAction>().state(), ActionState::Fired);
+}
+
+fn completed(_trigger: Trigger<Completed<DummyAction>>, mut events: ResMut<TriggeredEvents>) {
+    events.completed += 1;
+}
+
+fn canceled(_trigger: Trigger<Canceled<HoldAction>>, mut events: ResMut<TriggeredEvents>) {
+    events.canceled += 1;
+}
+
+#[derive(Resource, Default)]
+struct TriggeredEvents {
+    completed: usize,
+    canceled: usize,
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<DummyAction>().to(DummyAction::KEY);
+        ctx.bind::<HoldAction>()
+            .to(HoldAction::KEY)
+            .with_conditions(Hold::new(1.0));
+        ctx.bind::<ResetAction>().to(ResetAction::KEY);
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct HoldAction;
+
+impl HoldAction {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+struct ResetAction;
+
+impl ResetAction {
+    const KEY: KeyCode = KeyCode::KeyC;
+}
@@ -0,0 +1,186 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn gated_by_other_entity() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Leader>()
+        .add_input_context::<Follower>();
+
+    let leader = app.world_mut().spawn(Leader).id();
+    let follower = app.world_mut().spawn(Follower(leader)).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(FollowerAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<Follower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "shouldn't fire without the leader action"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(LeaderAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<Follower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "should read the leader state from the previous update"
+    );
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<Follower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(action.state(), ActionState::Fired);
+
+    app.world_mut().despawn(leader);
+
+    app.update();
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<Follower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "shouldn't fire after the leader removal"
+    );
+}
+
+#[test]
+fn chord() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Leader>()
+        .add_input_context::<ChordFollower>();
+
+    let leader = app.world_mut().spawn(Leader).id();
+    let follower = app.world_mut().spawn(ChordFollower(leader)).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(FollowerAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<ChordFollower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(action.state(), ActionState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(LeaderAction::KEY);
+
+    app.update();
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<ChordFollower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::Fired,
+        "should inherit the leader action state"
+    );
+
+    app.world_mut().despawn(leader);
+
+    app.update();
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<ChordFollower>(follower)
+        .action::<FollowerAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "shouldn't fire after the leader removal"
+    );
+}
+
+#[derive(Debug, Component)]
+struct Leader;
+
+impl InputContext for Leader {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<LeaderAction>().to(LeaderAction::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, Component, Deref)]
+struct Follower(Entity);
+
+impl InputContext for Follower {
+    fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        let leader = **world.get::<Self>(entity).unwrap();
+        ctx.bind::<FollowerAction>()
+            .to(FollowerAction::KEY)
+            .with_conditions(WhenEntityAction::new::<LeaderAction>(leader));
+
+        ctx
+    }
+}
+
+#[derive(Debug, Component, Deref)]
+struct ChordFollower(Entity);
+
+impl InputContext for ChordFollower {
+    fn context_instance(world: &World, entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        let leader = **world.get::<Self>(entity).unwrap();
+        ctx.bind::<FollowerAction>()
+            .to(FollowerAction::KEY)
+            .with_conditions(Chord::<LeaderAction>::default().with_entity(leader));
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct LeaderAction;
+
+impl LeaderAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct FollowerAction;
+
+impl FollowerAction {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
@@ -0,0 +1,152 @@
+use std::any::TypeId;
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn started_and_fired_same_frame() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<TriggeredEvents>()
+        .add_observer(
+            |_trigger: Trigger<Started<DummyAction>>, mut events: ResMut<TriggeredEvents>| {
+                events.insert(ActionEvents::STARTED);
+            },
+        )
+        .add_observer(
+            |_trigger: Trigger<Fired<DummyAction>>, mut events: ResMut<TriggeredEvents>| {
+                events.insert(ActionEvents::FIRED);
+            },
+        );
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    assert!(app.world().resource::<TriggeredEvents>().is_empty());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<TriggeredEvents>(),
+        ActionEvents::STARTED | ActionEvents::FIRED,
+        "both events should be triggered on the press frame"
+    );
+}
+
+#[test]
+fn untyped() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<UntypedEvents>()
+        .add_observer(
+            |trigger: Trigger<AnyActionEvent>, mut events: ResMut<UntypedEvents>| {
+                let event = trigger.event();
+                events.push((event.type_id, event.events, event.value));
+            },
+        );
+
+    app.world_mut().spawn(DummyContext);
+
+    app.update();
+
+    assert!(app.world().resource::<UntypedEvents>().is_empty());
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(DummyAction::KEY);
+    keys.press(UntypedAction::KEY);
+    keys.press(OtherUntypedAction::KEY);
+
+    app.update();
+
+    let mut events = app.world_mut().resource_mut::<UntypedEvents>();
+    assert_eq!(
+        **events,
+        [
+            (
+                TypeId::of::<UntypedAction>(),
+                ActionEvents::STARTED | ActionEvents::FIRED,
+                true.into()
+            ),
+            (
+                TypeId::of::<OtherUntypedAction>(),
+                ActionEvents::STARTED | ActionEvents::FIRED,
+                Vec2::Y.into()
+            ),
+        ],
+        "only actions with untyped events enabled should trigger them"
+    );
+    events.clear();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(UntypedAction::KEY);
+
+    app.update();
+
+    let events = app.world().resource::<UntypedEvents>();
+    assert_eq!(
+        **events,
+        [
+            (
+                TypeId::of::<UntypedAction>(),
+                ActionEvents::COMPLETED,
+                false.into()
+            ),
+            (
+                TypeId::of::<OtherUntypedAction>(),
+                ActionEvents::FIRED,
+                Vec2::Y.into()
+            ),
+        ]
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct TriggeredEvents(ActionEvents);
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct UntypedEvents(Vec<(TypeId, ActionEvents, ActionValue)>);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to(DummyAction::KEY);
+        ctx.bind::<UntypedAction>().to(UntypedAction::KEY);
+        ctx.bind::<OtherUntypedAction>()
+            .to(OtherUntypedAction::KEY.with_modifiers(SwizzleAxis::YXZ));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, emit_untyped = true)]
+struct UntypedAction;
+
+impl UntypedAction {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2, emit_untyped = true)]
+struct OtherUntypedAction;
+
+impl OtherUntypedAction {
+    const KEY: KeyCode = KeyCode::KeyC;
+}
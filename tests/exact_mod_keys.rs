@@ -0,0 +1,148 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn exact() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Exact>().state(), ActionState::Fired);
+    assert_eq!(ctx.action::<WithMod>().state(), ActionState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ControlLeft);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Exact>().state(),
+        ActionState::None,
+        "input shouldn't fire with additional modifiers"
+    );
+    assert_eq!(ctx.action::<WithMod>().state(), ActionState::Fired);
+}
+
+#[test]
+fn inexact() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::ControlLeft);
+    keys.press(KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Inexact>().state(),
+        ActionState::Fired,
+        "by default additional modifiers should be ignored"
+    );
+}
+
+#[test]
+fn modifier_as_key() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ControlLeft);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<ExactModifier>().state(),
+        ActionState::Fired,
+        "bound modifier key shouldn't count as an additional modifier"
+    );
+    assert_eq!(ctx.action::<ModifierWithMod>().state(), ActionState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ShiftLeft);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<ExactModifier>().state(),
+        ActionState::None,
+        "input shouldn't fire with additional modifiers"
+    );
+    assert_eq!(ctx.action::<ModifierWithMod>().state(), ActionState::Fired);
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<WithMod>()
+            .to(KEY.with_mod_keys(ModKeys::CONTROL).with_exact_mod_keys());
+        ctx.bind::<Exact>().to(KEY.with_exact_mod_keys());
+        ctx.bind::<Inexact>().to(KEY);
+        ctx.bind::<ExactModifier>()
+            .to(KeyCode::ControlLeft.with_exact_mod_keys());
+        ctx.bind::<ModifierWithMod>().to(KeyCode::ShiftLeft
+            .with_mod_keys(ModKeys::CONTROL)
+            .with_exact_mod_keys());
+
+        ctx
+    }
+}
+
+const KEY: KeyCode = KeyCode::KeyS;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct WithMod;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct Exact;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct Inexact;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct ExactModifier;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct ModifierWithMod;
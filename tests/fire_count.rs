@@ -0,0 +1,73 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn count() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    for _ in 0..3 {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KEY);
+
+        app.update();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KEY);
+
+        app.update();
+    }
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.fire_count(),
+        3,
+        "holding the input should be counted once"
+    );
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .action_mut::<DummyAction>()
+        .reset_fire_count();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(action.fire_count(), 1);
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to(KEY);
+        ctx
+    }
+}
+
+const KEY: KeyCode = KeyCode::KeyA;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
@@ -0,0 +1,71 @@
+use bevy::{ecs::event::Events, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn join() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, GamepadJoinPlugin::default()));
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.update();
+
+    let mut gamepad2 = app.world_mut().get_mut::<Gamepad>(gamepad_entity2).unwrap();
+    gamepad2.digital_mut().press(GamepadButton::Start);
+
+    app.update();
+
+    let mut events = app.world_mut().resource_mut::<Events<PlayerJoined>>();
+    let joined: Vec<_> = events.drain().collect();
+    assert_eq!(
+        joined,
+        [PlayerJoined {
+            gamepad: gamepad_entity2
+        }]
+    );
+
+    let join = app.world().resource::<GamepadJoin>();
+    assert!(join.is_assigned(gamepad_entity2));
+    assert!(!join.is_assigned(gamepad_entity1));
+
+    app.update();
+
+    let mut events = app.world_mut().resource_mut::<Events<PlayerJoined>>();
+    assert_eq!(
+        events.drain().count(),
+        0,
+        "assigned gamepad shouldn't join again"
+    );
+}
+
+#[test]
+fn disconnect() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, GamepadJoinPlugin::default()));
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.update();
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.digital_mut().press(GamepadButton::Start);
+
+    app.update();
+
+    assert!(app
+        .world()
+        .resource::<GamepadJoin>()
+        .is_assigned(gamepad_entity));
+
+    app.world_mut()
+        .entity_mut(gamepad_entity)
+        .remove::<Gamepad>();
+
+    app.update();
+
+    assert!(!app
+        .world()
+        .resource::<GamepadJoin>()
+        .is_assigned(gamepad_entity));
+}
@@ -0,0 +1,58 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn analog_value() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+    let context_entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Throttle::BUTTON, 0.4);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(context_entity)
+        .action::<Throttle>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(action.value(), 0.4.into());
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Throttle::BUTTON, 0.0);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(context_entity)
+        .action::<Throttle>();
+    assert_eq!(action.state(), ActionState::None);
+    assert_eq!(action.value(), 0.0.into());
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Throttle>()
+            .to(Input::gamepad_trigger(Throttle::BUTTON));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Throttle;
+
+impl Throttle {
+    const BUTTON: GamepadButton = GamepadButton::RightTrigger2;
+}
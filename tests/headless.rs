@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn mocked_movement() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<Unit>()
+        .add_observer(apply_movement);
+
+    let entity = app.world_mut().spawn(Unit).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<Unit>(entity)
+        .bind::<Move>()
+        .mock(ActionMock::new(
+            ActionState::Fired,
+            Vec2::X,
+            MockSpan::Duration(Duration::from_millis(300)),
+        ))
+        .mock(ActionMock::fired(Vec2::Y));
+
+    let expected = [
+        Vec3::X,
+        Vec3::X * 2.0,
+        Vec3::X * 3.0,
+        Vec3::new(3.0, 1.0, 0.0),
+        Vec3::new(3.0, 1.0, 0.0),
+    ];
+    for (update, expected_translation) in expected.into_iter().enumerate() {
+        app.update();
+
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert_eq!(
+            transform.translation, expected_translation,
+            "update {update}"
+        );
+    }
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<Unit>(entity).action::<Move>();
+    assert_eq!(action.state(), ActionState::None);
+    assert_eq!(action.fire_count(), 1);
+}
+
+fn apply_movement(trigger: Trigger<Fired<Move>>, mut units: Query<&mut Transform>) {
+    let mut transform = units.get_mut(trigger.entity()).unwrap();
+    transform.translation += trigger.value.extend(0.0);
+}
+
+#[derive(Debug, Component)]
+#[require(Transform)]
+struct Unit;
+
+impl InputContext for Unit {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Move>().to(Cardinal::wasd_keys());
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Move;
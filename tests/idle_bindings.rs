@@ -0,0 +1,73 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::{
+    action_value::ActionValue, input_context::context_instance::ActionsData, prelude::*,
+};
+
+#[test]
+fn skipped_evaluation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    for pressed in [false, true, true, false, false] {
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        if pressed {
+            keys.press(KEY);
+        } else {
+            keys.release(KEY);
+        }
+
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let ctx = instances.context::<DummyContext>(entity);
+        let skipped = ctx.action::<Skipped>();
+        let evaluated = ctx.action::<Evaluated>();
+        assert_eq!(skipped.state(), evaluated.state());
+        assert_eq!(skipped.value(), evaluated.value());
+        assert_eq!(skipped.events(), evaluated.events());
+    }
+}
+
+/// Forwards to the inner modifier, but always requires evaluation.
+#[derive(Debug)]
+struct Opaque<M>(M);
+
+impl<M: InputModifier> InputModifier for Opaque<M> {
+    fn apply(
+        &mut self,
+        actions: &ActionsData,
+        time: &Time<Virtual>,
+        value: ActionValue,
+    ) -> ActionValue {
+        self.0.apply(actions, time, value)
+    }
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<Skipped>()
+            .to(KEY.with_modifiers((Negate::all(), Scale::splat(2.0))));
+        ctx.bind::<Evaluated>()
+            .to(KEY.with_modifiers((Opaque(Negate::all()), Opaque(Scale::splat(2.0)))));
+
+        ctx
+    }
+}
+
+const KEY: KeyCode = KeyCode::KeyA;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32, consume_input = false)]
+struct Skipped;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32, consume_input = false)]
+struct Evaluated;
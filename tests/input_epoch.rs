@@ -0,0 +1,30 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn increment() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin));
+
+    assert_eq!(**app.world().resource::<InputEpoch>(), 0);
+
+    for expected in 1..=3 {
+        app.update();
+        assert_eq!(
+            **app.world().resource::<InputEpoch>(),
+            expected,
+            "epoch should increment once per update"
+        );
+    }
+}
+
+#[test]
+fn schedule_run() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin));
+
+    app.world_mut().run_schedule(PreUpdate);
+    app.world_mut().run_schedule(PreUpdate);
+
+    assert_eq!(**app.world().resource::<InputEpoch>(), 2);
+}
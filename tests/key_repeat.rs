@@ -0,0 +1,92 @@
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn repeat() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut().send_event(key_event(false));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(action.state(), ActionState::Fired, "should fire on press");
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "shouldn't fire while held without repeats"
+    );
+
+    for _ in 0..2 {
+        app.world_mut().send_event(key_event(true));
+
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let action = instances
+            .context::<DummyContext>(entity)
+            .action::<DummyAction>();
+        assert_eq!(action.state(), ActionState::Fired, "should fire on repeat");
+        assert!(action.events().contains(ActionEvents::STARTED));
+
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let action = instances
+            .context::<DummyContext>(entity)
+            .action::<DummyAction>();
+        assert_eq!(action.state(), ActionState::None);
+    }
+}
+
+fn key_event(repeat: bool) -> KeyboardInput {
+    KeyboardInput {
+        key_code: DummyAction::KEY,
+        logical_key: Key::Backspace,
+        state: ButtonState::Pressed,
+        repeat,
+        window: Entity::PLACEHOLDER,
+    }
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>()
+            .to(Input::key_repeat(DummyAction::KEY));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::Backspace;
+}
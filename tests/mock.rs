@@ -0,0 +1,258 @@
+use std::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn sequence() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<DummyAction>()
+        .mock(ActionMock::new(
+            ActionState::Fired,
+            true,
+            MockSpan::Duration(Duration::from_millis(300)),
+        ))
+        .mock(ActionMock::new(
+            ActionState::None,
+            false,
+            MockSpan::Updates(1),
+        ))
+        .mock(ActionMock::new(
+            ActionState::Ongoing,
+            true,
+            MockSpan::Updates(2),
+        ));
+
+    let expected = [
+        ActionState::Fired,
+        ActionState::Fired,
+        ActionState::Fired,
+        ActionState::None,
+        ActionState::Ongoing,
+        ActionState::Ongoing,
+    ];
+    for (update, expected_state) in expected.into_iter().enumerate() {
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let action = instances
+            .context::<DummyContext>(entity)
+            .action::<DummyAction>();
+        assert_eq!(action.state(), expected_state, "update {update}");
+    }
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::Fired,
+        "should read input after all mocks expire"
+    );
+}
+
+#[test]
+fn clear() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<DummyAction>()
+        .mock(ActionMock::new(
+            ActionState::Fired,
+            true,
+            MockSpan::Updates(10),
+        ))
+        .clear_mocks();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(action.state(), ActionState::None);
+}
+
+#[test]
+fn remaining() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    let action_bind = instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<DummyAction>();
+    assert!(action_bind.active_mock().is_none());
+
+    action_bind.mock(ActionMock::new(
+        ActionState::Fired,
+        true,
+        MockSpan::Duration(Duration::from_millis(300)),
+    ));
+
+    for expected_millis in [200, 100] {
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let mock = instances
+            .context::<DummyContext>(entity)
+            .action_bind::<DummyAction>()
+            .active_mock()
+            .expect("mock should be active until its span expires");
+        assert_eq!(
+            mock.remaining(),
+            MockSpan::Duration(Duration::from_millis(expected_millis))
+        );
+    }
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action_bind = instances
+        .context::<DummyContext>(entity)
+        .action_bind::<DummyAction>();
+    assert!(action_bind.active_mock().is_none());
+}
+
+#[test]
+fn once() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<DummyAction>()
+        .mock(ActionMock::once(ActionState::Ongoing, true))
+        .mock(ActionMock::fired(true));
+
+    for expected_state in [ActionState::Ongoing, ActionState::Fired] {
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let action = instances
+            .context::<DummyContext>(entity)
+            .action::<DummyAction>();
+        assert_eq!(action.state(), expected_state);
+        assert!(action.value().as_bool());
+    }
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::None,
+        "should read input on the next update"
+    );
+}
+
+#[test]
+fn zero_span() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<DummyAction>()
+        .mock(ActionMock::new(
+            ActionState::None,
+            false,
+            MockSpan::Updates(0),
+        ))
+        .mock(ActionMock::new(
+            ActionState::None,
+            false,
+            MockSpan::Duration(Duration::ZERO),
+        ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action_bind = instances
+        .context::<DummyContext>(entity)
+        .action_bind::<DummyAction>();
+    assert!(action_bind.active_mock().is_none());
+
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.state(),
+        ActionState::Fired,
+        "mocks with zero span should be skipped"
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>().to(DummyAction::KEY);
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
@@ -0,0 +1,111 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn directives() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity1 = app.world_mut().spawn(DummyContext).id();
+    let entity2 = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut directives = app.world_mut().resource_mut::<MockDirectives>();
+    directives.insert::<Jump>(entity1, ActionState::Fired, true);
+    directives.insert::<Move>(entity2, ActionState::Ongoing, Vec2::Y);
+
+    for _ in 0..2 {
+        app.update();
+
+        let instances = app.world().resource::<ContextInstances>();
+        let ctx = instances.context::<DummyContext>(entity1);
+        let action = ctx.action::<Jump>();
+        assert_eq!(action.state(), ActionState::Fired);
+        assert_eq!(action.value(), true.into());
+        assert_eq!(ctx.action::<Move>().state(), ActionState::None);
+
+        let ctx = instances.context::<DummyContext>(entity2);
+        let action = ctx.action::<Move>();
+        assert_eq!(action.state(), ActionState::Ongoing);
+        assert_eq!(action.value(), Vec2::Y.into());
+        assert_eq!(ctx.action::<Jump>().state(), ActionState::None);
+    }
+
+    let mut directives = app.world_mut().resource_mut::<MockDirectives>();
+    assert!(directives.remove::<Jump>(entity1).is_some());
+    directives.clear();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    for entity in [entity1, entity2] {
+        let ctx = instances.context::<DummyContext>(entity);
+        assert_eq!(
+            ctx.action::<Jump>().state(),
+            ActionState::None,
+            "removed directives shouldn't affect actions"
+        );
+        assert_eq!(ctx.action::<Move>().state(), ActionState::None);
+    }
+}
+
+#[test]
+fn priority_over_mocks() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .bind::<Jump>()
+        .mock(ActionMock::fired(true));
+
+    app.world_mut()
+        .resource_mut::<MockDirectives>()
+        .insert::<Jump>(entity, ActionState::Ongoing, true);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Jump>().state(), ActionState::Ongoing);
+
+    app.world_mut().resource_mut::<MockDirectives>().clear();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Jump>().state(),
+        ActionState::Fired,
+        "queued mocks should resume after the directive is removed"
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Jump>().to(KeyCode::Space);
+        ctx.bind::<Move>().to(Cardinal::wasd_keys());
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Jump;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Move;
@@ -0,0 +1,59 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn insertion_order() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyA);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Appended>().value(),
+        0.0.into(),
+        "scaled value should be discarded by the dead zone"
+    );
+    assert_eq!(ctx.action::<Inserted>().value(), 0.1.into());
+
+    let modifiers = ctx.action_bind::<Inserted>().modifiers();
+    assert_eq!(modifiers.len(), 2);
+    assert!(format!("{:?}", modifiers[0]).starts_with("DeadZone"));
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<Appended>()
+            .to(KeyCode::KeyA)
+            .with_modifiers(Scale::splat(0.1))
+            .with_modifiers(DeadZone::default());
+
+        ctx.bind::<Inserted>()
+            .to(KeyCode::KeyA)
+            .with_modifiers(Scale::splat(0.1))
+            .insert_modifiers(0, DeadZone::default());
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32, consume_input = false)]
+struct Appended;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32, consume_input = false)]
+struct Inserted;
@@ -0,0 +1,99 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn passthrough() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Global>()
+        .add_input_context::<Gameplay>();
+
+    let entity = app.world_mut().spawn((Global, Gameplay)).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(PASSTHROUGH_KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let global = instances.context::<Global>(entity);
+    assert_eq!(global.action::<Screenshot>().state(), ActionState::Fired);
+
+    let gameplay = instances.context::<Gameplay>(entity);
+    assert_eq!(
+        gameplay.action::<Attack>().state(),
+        ActionState::Fired,
+        "passthrough input shouldn't be consumed"
+    );
+}
+
+#[test]
+fn consume() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Global>()
+        .add_input_context::<Gameplay>();
+
+    let entity = app.world_mut().spawn((Global, Gameplay)).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(CONSUME_KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let global = instances.context::<Global>(entity);
+    assert_eq!(global.action::<Screenshot>().state(), ActionState::Fired);
+
+    let gameplay = instances.context::<Gameplay>(entity);
+    assert_eq!(
+        gameplay.action::<Attack>().state(),
+        ActionState::None,
+        "other bindings of the action should still be consumed"
+    );
+}
+
+#[derive(Debug, Component)]
+struct Global;
+
+impl InputContext for Global {
+    const PRIORITY: isize = Gameplay::PRIORITY + 1;
+
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Screenshot>()
+            .to((PASSTHROUGH_KEY.with_passthrough(), CONSUME_KEY));
+        ctx
+    }
+}
+
+#[derive(Debug, Component)]
+struct Gameplay;
+
+impl InputContext for Gameplay {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Attack>().to((PASSTHROUGH_KEY, CONSUME_KEY));
+        ctx
+    }
+}
+
+/// A key that fires [`Screenshot`] without blocking [`Attack`].
+const PASSTHROUGH_KEY: KeyCode = KeyCode::F12;
+
+/// A key that is consumed by [`Screenshot`].
+const CONSUME_KEY: KeyCode = KeyCode::PrintScreen;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = true)]
+struct Screenshot;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Attack;
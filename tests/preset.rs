@@ -1,4 +1,7 @@
-use bevy::{input::InputPlugin, prelude::*};
+use bevy::{
+    input::{mouse::MouseMotion, InputPlugin},
+    prelude::*,
+};
 use bevy_enhanced_input::prelude::*;
 
 #[test]
@@ -121,6 +124,121 @@ fn sticks() {
     }
 }
 
+#[test]
+fn dual() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+    let context_entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DualAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(context_entity);
+    assert_eq!(ctx.action::<DualAction>().state(), ActionState::Fired);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(DualAction::KEY);
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.digital_mut().press(DualAction::BUTTON);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(context_entity);
+    assert_eq!(ctx.action::<DualAction>().state(), ActionState::Fired);
+}
+
+#[test]
+fn drag_pan() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut().send_event(MouseMotion { delta: Vec2::ONE });
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Pan>().value(),
+        Vec2::ZERO.into(),
+        "motion alone shouldn't pan"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(Drag::BUTTON);
+    app.world_mut().send_event(MouseMotion { delta: Vec2::ONE });
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Pan>().value(),
+        (Vec2::ONE * Pan::SENSITIVITY).into()
+    );
+}
+
+#[test]
+fn move_with_sprint() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Walk::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<Walk>().value(), UP.into());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Sprint::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(
+        ctx.action::<Walk>().value(),
+        (UP * Walk::SPRINT_SCALE).into()
+    );
+}
+
+#[test]
+fn dual_display() {
+    let dual = Dual::new(
+        KeyCode::Space.with_mod_keys(ModKeys::SHIFT),
+        GamepadButton::South,
+    );
+    assert_eq!(dual.to_string(), "Shift + Space / Gamepad South");
+}
+
 const UP: Vec2 = Vec2::new(0.0, 1.0);
 const LEFT: Vec2 = Vec2::new(-1.0, 0.0);
 const DOWN: Vec2 = Vec2::new(0.0, -1.0);
@@ -144,6 +262,20 @@ impl InputContext for DummyContext {
             GamepadStick::Left,
             GamepadStick::Right,
         ));
+        ctx.bind::<DualAction>()
+            .to(Dual::new(DualAction::KEY, DualAction::BUTTON));
+        ctx.bind::<Drag>().to(Drag::BUTTON);
+        ctx.bind::<Pan>().to(DragPan::<Drag>::new(Pan::SENSITIVITY));
+        ctx.bind::<Sprint>().to(Sprint::KEY);
+        ctx.bind::<Walk>().to(MoveWithSprint::<Sprint, _>::new(
+            Cardinal {
+                north: Walk::KEY,
+                east: KeyCode::KeyL,
+                south: KeyCode::KeyK,
+                west: KeyCode::KeyJ,
+            },
+            Walk::SPRINT_SCALE,
+        ));
 
         ctx
     }
@@ -152,3 +284,45 @@ impl InputContext for DummyContext {
 #[derive(Debug, InputAction)]
 #[input_action(output = Vec2, consume_input = true)]
 struct DummyAction;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DualAction;
+
+impl DualAction {
+    const KEY: KeyCode = KeyCode::Enter;
+    const BUTTON: GamepadButton = GamepadButton::Start;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Drag;
+
+impl Drag {
+    const BUTTON: MouseButton = MouseButton::Middle;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Pan;
+
+impl Pan {
+    const SENSITIVITY: f32 = 0.5;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Sprint;
+
+impl Sprint {
+    const KEY: KeyCode = KeyCode::ShiftRight;
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Walk;
+
+impl Walk {
+    const KEY: KeyCode = KeyCode::KeyI;
+    const SPRINT_SCALE: f32 = 2.0;
+}
@@ -40,6 +40,153 @@ fn prioritization() {
     );
 }
 
+#[test]
+fn runtime_change() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .add_input_context::<Second>();
+
+    let entity = app.world_mut().spawn((First, Second)).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances.set_priority::<Second>(First::PRIORITY + 1);
+    assert_eq!(instances.priority::<Second>(), First::PRIORITY + 1);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(CONSUME_KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+
+    let second = instances.context::<Second>(entity);
+    assert_eq!(second.action::<SecondConsume>().state(), ActionState::Fired);
+
+    let first = instances.context::<First>(entity);
+    assert_eq!(
+        first.action::<FirstConsume>().state(),
+        ActionState::None,
+        "action should be consumed by context with a raised priority"
+    );
+
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<Second>()
+        .insert(Second);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+
+    let second = instances.context::<Second>(entity);
+    assert_eq!(
+        second.action::<SecondConsume>().state(),
+        ActionState::Fired,
+        "override should persist after re-instantiation"
+    );
+
+    let first = instances.context::<First>(entity);
+    assert_eq!(first.action::<FirstConsume>().state(), ActionState::None);
+}
+
+#[test]
+fn removal() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .add_input_context::<Second>();
+
+    let entity = app.world_mut().spawn((First, Second)).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(CONSUME_KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let second = instances.context::<Second>(entity);
+    assert_eq!(second.action::<SecondConsume>().state(), ActionState::None);
+
+    app.world_mut().entity_mut(entity).remove::<First>();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let second = instances.context::<Second>(entity);
+    assert_eq!(
+        second.action::<SecondConsume>().state(),
+        ActionState::Fired,
+        "input consumed by the removed context should be released immediately"
+    );
+
+    app.world_mut().entity_mut(entity).insert(First);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let first = instances.context::<First>(entity);
+    assert_eq!(first.action::<FirstConsume>().state(), ActionState::Fired);
+
+    let second = instances.context::<Second>(entity);
+    assert_eq!(second.action::<SecondConsume>().state(), ActionState::None);
+}
+
+#[test]
+fn disabled() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .add_input_context::<Second>();
+
+    let entity = app.world_mut().spawn((First, Second)).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(CONSUME_KEY);
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<First>(entity)
+        .bind::<FirstConsume>()
+        .set_enabled(false);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let second = instances.context::<Second>(entity);
+    assert_eq!(
+        second.action::<SecondConsume>().state(),
+        ActionState::Fired,
+        "disabled action shouldn't consume inputs"
+    );
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<First>(entity)
+        .bind::<FirstConsume>()
+        .set_enabled(true);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let first = instances.context::<First>(entity);
+    assert_eq!(first.action::<FirstConsume>().state(), ActionState::Fired);
+
+    let second = instances.context::<Second>(entity);
+    assert_eq!(second.action::<SecondConsume>().state(), ActionState::None);
+}
+
 #[derive(Debug, Component)]
 struct First;
 
@@ -0,0 +1,76 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn capture() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>()
+        .init_resource::<FiredValue>()
+        .add_observer(
+            |trigger: Trigger<Fired<Charge>>, mut fired_value: ResMut<FiredValue>| {
+                **fired_value = Some(trigger.value);
+            },
+        );
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Charge>();
+    assert_eq!(action.state(), ActionState::Ongoing);
+    assert_eq!(action.value(), CHARGE.into());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Charge>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(
+        action.value(),
+        CHARGE.into(),
+        "value should be captured before the release"
+    );
+    assert_eq!(
+        *app.world().resource::<FiredValue>(),
+        FiredValue(Some(CHARGE))
+    );
+}
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<Charge>()
+            .to(KEY)
+            .with_modifiers(Scale::splat(CHARGE))
+            .with_conditions(Release::default().capture_value(true));
+
+        ctx
+    }
+}
+
+const KEY: KeyCode = KeyCode::Space;
+
+const CHARGE: f32 = 0.75;
+
+#[derive(Resource, Default, Deref, DerefMut, PartialEq, Debug)]
+struct FiredValue(Option<f32>);
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+struct Charge;
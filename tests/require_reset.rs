@@ -108,6 +108,40 @@ fn switching() {
     assert_eq!(second.action::<DummyAction>().state(), ActionState::Fired);
 }
 
+#[test]
+fn held_on_spawn() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Second>()
+        .add_input_context::<NoReset>();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    app.update();
+
+    let entity = app.world_mut().spawn((Second, NoReset)).id();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+
+    let second = instances.context::<Second>(entity);
+    assert_eq!(
+        second.action::<DummyAction>().state(),
+        ActionState::None,
+        "held input should be ignored until release"
+    );
+
+    let no_reset = instances.context::<NoReset>(entity);
+    assert_eq!(
+        no_reset.action::<DummyAction>().state(),
+        ActionState::Fired,
+        "held input should be respected immediately when opted out"
+    );
+}
+
 #[derive(Debug, Component)]
 struct First;
 
@@ -132,6 +166,21 @@ impl InputContext for Second {
     }
 }
 
+#[derive(Debug, Component)]
+struct NoReset;
+
+impl InputContext for NoReset {
+    const PRIORITY: isize = Second::PRIORITY - 1;
+
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>()
+            .with_require_reset(false)
+            .to(DummyAction::KEY);
+        ctx
+    }
+}
+
 #[derive(Debug, InputAction)]
 #[input_action(output = bool, require_reset = true)]
 struct DummyAction;
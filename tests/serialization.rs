@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn round_trip() {
+    let config = Config {
+        press: Press::new(0.3),
+        release: Release::default().capture_value(true),
+        hold: Hold::new(1.0).one_shot(true),
+        hold_and_release: HoldAndRelease::new(0.5),
+        pulse: Pulse::new(0.2),
+        tap: Tap::new(0.4),
+        chord: Chord::default(),
+        block_by: BlockBy::events_only(),
+        scale: Scale::splat(2.0),
+        dead_zone: DeadZone::new(DeadZoneKind::Axial).with_lower_threshold(0.1),
+        smooth_nudge: SmoothNudge::new(4.0),
+        negate: Negate::y(),
+        clamp: ClampThenNormalize::new(0.5),
+        exponential_curve: ExponentialCurve::splat(2.0),
+        delta_scale: DeltaScale::splat(3.0),
+        swizzle_axis: SwizzleAxis::ZXY,
+        lookup_table: LookupTable::new([0.0, 0.5, 1.0]),
+    };
+
+    let serialized = ron::to_string(&config).unwrap();
+    let config: Config = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(config.press.actuation, 0.3);
+    assert!(config.release.capture_value);
+    assert_eq!(config.hold.hold_time, 1.0);
+    assert!(config.hold.one_shot);
+    assert_eq!(config.hold_and_release.hold_time, 0.5);
+    assert_eq!(config.pulse.interval, 0.2);
+    assert_eq!(config.tap.release_time, 0.4);
+    assert_eq!(config.chord.entity, None);
+    assert!(config.block_by.events_only);
+    assert_eq!(config.scale.factor, Vec3::splat(2.0));
+    assert!(matches!(config.dead_zone.kind, DeadZoneKind::Axial));
+    assert_eq!(config.dead_zone.lower_threshold, 0.1);
+    assert_eq!(config.smooth_nudge.decay_rate, 4.0);
+    assert!(!config.negate.x);
+    assert!(config.negate.y);
+    assert!(!config.negate.z);
+    assert_eq!(config.clamp.max_length, 0.5);
+    assert_eq!(config.exponential_curve.exp, Vec3::splat(2.0));
+    assert_eq!(config.delta_scale.factor, Vec3::splat(3.0));
+    assert!(matches!(config.swizzle_axis, SwizzleAxis::ZXY));
+    assert_eq!(config.lookup_table.samples(), [0.0, 0.5, 1.0]);
+}
+
+#[test]
+fn short_lookup_table() {
+    assert!(ron::from_str::<LookupTable>("[]").is_err());
+    assert!(ron::from_str::<LookupTable>("[1.0]").is_err());
+    assert!(ron::from_str::<LookupTable>("[0.0, 1.0]").is_ok());
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Config {
+    press: Press,
+    release: Release,
+    hold: Hold,
+    hold_and_release: HoldAndRelease,
+    pulse: Pulse,
+    tap: Tap,
+    chord: Chord<Jump>,
+    block_by: BlockBy<Jump>,
+    scale: Scale,
+    dead_zone: DeadZone,
+    smooth_nudge: SmoothNudge,
+    negate: Negate,
+    clamp: ClampThenNormalize,
+    exponential_curve: ExponentialCurve,
+    delta_scale: DeltaScale,
+    swizzle_axis: SwizzleAxis,
+    lookup_table: LookupTable,
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Jump;
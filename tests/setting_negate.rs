@@ -0,0 +1,74 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn toggle_setting() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(InvertY(false))
+        .add_input_setting::<InvertY>()
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Look>();
+    assert_eq!(action.value(), Vec2::Y.into());
+
+    **app.world_mut().resource_mut::<InvertY>() = true;
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Look>();
+    assert_eq!(
+        action.value(),
+        Vec2::NEG_Y.into(),
+        "Y should be inverted after enabling the setting"
+    );
+
+    assert_eq!(
+        app.world().resource::<InputSettings>().get::<InvertY>(),
+        Some(true)
+    );
+
+    app.world_mut().remove_resource::<InvertY>();
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Look>();
+    assert_eq!(
+        action.value(),
+        Vec2::Y.into(),
+        "missing setting should be treated as disabled"
+    );
+}
+
+#[derive(Resource, Deref, DerefMut)]
+struct InvertY(bool);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<Look>()
+            .to(Cardinal::wasd_keys())
+            .with_modifiers(SettingNegate::<InvertY>::new(Negate::y()));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Look;
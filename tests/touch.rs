@@ -0,0 +1,167 @@
+use bevy::{
+    input::{
+        touch::{TouchInput, TouchPhase},
+        InputPlugin,
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn press() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .send_event(touch_input(TouchPhase::Started, Vec2::ZERO, FINGER));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<AnyPress>().state(), ActionState::Fired);
+    assert_eq!(ctx.action::<FingerPress>().state(), ActionState::Fired);
+
+    app.world_mut()
+        .send_event(touch_input(TouchPhase::Ended, Vec2::ZERO, FINGER));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<AnyPress>().state(), ActionState::None);
+    assert_eq!(ctx.action::<FingerPress>().state(), ActionState::None);
+}
+
+#[test]
+fn other_finger() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .send_event(touch_input(TouchPhase::Started, Vec2::ZERO, FINGER + 1));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<AnyPress>().state(), ActionState::Fired);
+    assert_eq!(
+        ctx.action::<FingerPress>().state(),
+        ActionState::None,
+        "should react only to the assigned finger"
+    );
+}
+
+#[test]
+fn motion() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .send_event(touch_input(TouchPhase::Started, Vec2::ZERO, FINGER));
+
+    app.update();
+
+    let value = Vec2::new(2.0, -1.0);
+    app.world_mut()
+        .send_event(touch_input(TouchPhase::Moved, value, FINGER));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Motion>();
+    assert_eq!(action.state(), ActionState::Fired);
+    assert_eq!(action.value(), value.into());
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances.context::<DummyContext>(entity).action::<Motion>();
+    assert_eq!(
+        action.value(),
+        Vec2::ZERO.into(),
+        "should output delta only for the frame with movement"
+    );
+}
+
+#[test]
+fn disabled() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<DummyContext>();
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    let mut instances = app.world_mut().resource_mut::<ContextInstances>();
+    instances
+        .context_mut::<DummyContext>(entity)
+        .set_touch(false);
+
+    app.world_mut()
+        .send_event(touch_input(TouchPhase::Started, Vec2::ZERO, FINGER));
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let ctx = instances.context::<DummyContext>(entity);
+    assert_eq!(ctx.action::<AnyPress>().state(), ActionState::None);
+}
+
+fn touch_input(phase: TouchPhase, position: Vec2, id: u64) -> TouchInput {
+    TouchInput {
+        phase,
+        position,
+        window: Entity::PLACEHOLDER,
+        force: None,
+        id,
+    }
+}
+
+const FINGER: u64 = 1;
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+
+        ctx.bind::<AnyPress>().to(Input::touch_press());
+        ctx.bind::<FingerPress>().to(Input::TouchPress {
+            finger: Some(FINGER),
+        });
+        ctx.bind::<Motion>().to(Input::touch_motion());
+
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct AnyPress;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, consume_input = false)]
+struct FingerPress;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct Motion;
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+
+#[test]
+fn pulse() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<FiredCount>()
+        .add_input_context::<DummyContext>()
+        .add_observer(
+            |_trigger: Trigger<Fired<DummyAction>>, mut count: ResMut<FiredCount>| {
+                **count += 1;
+            },
+        );
+
+    let entity = app.world_mut().spawn(DummyContext).id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(DummyAction::KEY);
+
+    for _ in 0..8 {
+        app.update();
+
+        let fired_count = **app.world().resource::<FiredCount>();
+        let instances = app.world().resource::<ContextInstances>();
+        let action = instances
+            .context::<DummyContext>(entity)
+            .action::<DummyAction>();
+        assert_eq!(action.trigger_count(), fired_count);
+    }
+
+    assert!(
+        **app.world().resource::<FiredCount>() > 1,
+        "action should pulse multiple times"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(DummyAction::KEY);
+
+    app.update();
+
+    let instances = app.world().resource::<ContextInstances>();
+    let action = instances
+        .context::<DummyContext>(entity)
+        .action::<DummyAction>();
+    assert_eq!(
+        action.trigger_count(),
+        0,
+        "count should reset when the action becomes inactive"
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct FiredCount(u32);
+
+#[derive(Debug, Component)]
+struct DummyContext;
+
+impl InputContext for DummyContext {
+    fn context_instance(_world: &World, _entity: Entity) -> ContextInstance {
+        let mut ctx = ContextInstance::default();
+        ctx.bind::<DummyAction>()
+            .to(DummyAction::KEY)
+            // Longer than 2 frames, so each pulse is separated by `Ongoing`.
+            .with_conditions(Pulse::new(0.25));
+        ctx
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct DummyAction;
+
+impl DummyAction {
+    const KEY: KeyCode = KeyCode::KeyA;
+}